@@ -0,0 +1,202 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::Write,
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{InputSource, Solution};
+
+use crate::days::{self, Day};
+
+/// The fastest of several runs of each stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    fn stages(&self) -> [Duration; 3] {
+        [self.parse, self.part1, self.part2]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Row {
+    day: u8,
+    input: String,
+    timings: Timings,
+}
+
+fn fastest<T>(runs: u32, mut f: impl FnMut() -> T) -> Duration {
+    (0..runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .expect("at least one run")
+}
+
+pub fn time<S: Solution>(input: &str, runs: u32) -> Result<Timings, Box<dyn Error>> {
+    let data = S::parse(input)?;
+    // only inputs with answers are worth timing
+    S::part1(&data)?;
+    S::part2(&data)?;
+    Ok(Timings {
+        parse: fastest(runs, || S::parse(black_box(input))),
+        part1: fastest(runs, || S::part1(black_box(&data))),
+        part2: fastest(runs, || S::part2(black_box(&data))),
+    })
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos();
+    match nanos {
+        0..1_000 => format!("{}ns", nanos),
+        1_000..1_000_000 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", d.as_secs_f64()),
+    }
+}
+
+fn cell(now: Duration, before: Option<Duration>) -> String {
+    match before {
+        Some(b) if !b.is_zero() => {
+            let change = (now.as_secs_f64() / b.as_secs_f64() - 1.0) * 100.0;
+            format!("{} ({:+.1}%)", format_duration(now), change)
+        }
+        _ => format_duration(now),
+    }
+}
+
+fn to_tsv(rows: &[Row]) -> String {
+    let mut s = String::from("# day\tinput\tparse_ns\tpart1_ns\tpart2_ns\n");
+    for row in rows {
+        let [parse, part1, part2] = row.timings.stages().map(|d| d.as_nanos());
+        writeln!(s, "{}\t{}\t{}\t{}\t{}", row.day, row.input, parse, part1, part2)
+            .expect("writing to a string");
+    }
+    s
+}
+
+fn from_tsv(s: &str) -> Result<Vec<Row>, String> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.starts_with('#') && !l.trim().is_empty())
+        .map(|(n, l)| {
+            let error = || format!("baseline line {}: expected `day input parse part1 part2`", n + 1);
+            let fields: Vec<_> = l.split('\t').collect();
+            let [day, input, parse, part1, part2] = fields[..] else {
+                return Err(error());
+            };
+            let nanos = |f: &str| f.parse().map(Duration::from_nanos).map_err(|_| error());
+            Ok(Row {
+                day: day.parse().map_err(|_| error())?,
+                input: input.to_string(),
+                timings: Timings {
+                    parse: nanos(parse)?,
+                    part1: nanos(part1)?,
+                    part2: nanos(part2)?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Times every day on its example and real input and prints a table. Each
+/// cell shows the change against `baseline` when one is given.
+pub fn run(
+    day: Option<u8>,
+    runs: u32,
+    profile: Option<&str>,
+    save: Option<&Path>,
+    baseline: Option<&Path>,
+) -> Result<(), Box<dyn Error>> {
+    let baseline: HashMap<_, _> = match baseline {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|e| format!("{}: {}", path.display(), e))
+            .and_then(|s| from_tsv(&s))?
+            .into_iter()
+            .map(|r| ((r.day, r.input), r.timings))
+            .collect(),
+        None => HashMap::new(),
+    };
+    let days: Vec<Day> = match day {
+        Some(n) => vec![days::get(n)?],
+        None => days::all().to_vec(),
+    };
+
+    println!("{:<4} {:<8} {:>20} {:>20} {:>20}", "day", "input", "parse", "part 1", "part 2");
+    let mut rows = Vec::new();
+    for day in days {
+        let sources = [
+            ("example", InputSource::profile("example")),
+            ("real", days::source(day.number, None, profile)),
+        ];
+        for (name, source) in sources {
+            let input = match source.read(day.number) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("skipping day {} {}: {}", day.number, name, e);
+                    continue;
+                }
+            };
+            let timings = (day.bench)(&input, runs)?;
+            let now = timings.stages();
+            let before = baseline.get(&(day.number, name.to_string())).map(Timings::stages);
+            let cells: Vec<_> = (0..3).map(|i| cell(now[i], before.map(|b| b[i]))).collect();
+            println!(
+                "d{:02}  {:<8} {:>20} {:>20} {:>20}",
+                day.number, name, cells[0], cells[1], cells[2]
+            );
+            rows.push(Row {
+                day: day.number,
+                input: name.to_string(),
+                timings,
+            });
+        }
+    }
+    if let Some(path) = save {
+        fs::write(path, to_tsv(&rows))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(850)), "850ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.56ms");
+        assert_eq!(format_duration(Duration::from_millis(1_230)), "1.23s");
+        assert_eq!(
+            cell(Duration::from_micros(90), Some(Duration::from_micros(100))),
+            "90.0µs (-10.0%)"
+        );
+    }
+
+    #[test]
+    fn test_tsv_round_trip() {
+        let rows = vec![Row {
+            day: 7,
+            input: "example".to_string(),
+            timings: Timings {
+                parse: Duration::from_nanos(1),
+                part1: Duration::from_nanos(20),
+                part2: Duration::from_nanos(300),
+            },
+        }];
+        assert_eq!(from_tsv(&to_tsv(&rows)), Ok(rows));
+        assert!(from_tsv("7\texample\t1\n").is_err());
+    }
+}
@@ -0,0 +1,219 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{
+    Explore, Frame, Image, InputSource, ParseError, Picture, Session, Solution, Visualize,
+};
+use rand::rngs::StdRng;
+
+use crate::{
+    bench::{self, Timings},
+    report::{Format, Report},
+};
+
+/// Parses an input and solves both parts, or only the given one.
+type Solver = fn(&str, Option<u8>) -> Result<Vec<Answer>, Box<dyn Error>>;
+
+/// Parses an input and times each stage over the given number of runs.
+type Bencher = fn(&str, u32) -> Result<Timings, Box<dyn Error>>;
+
+/// Parses an input and draws how it is solved.
+type Frames = fn(&str) -> Result<Vec<Frame>, Box<dyn Error>>;
+
+/// Parses an input and turns it into an image.
+type Picturer = fn(&str) -> Result<Image, Box<dyn Error>>;
+
+/// Parses an input and starts exploring it.
+pub type Starter = for<'a> fn(&'a str) -> Result<Box<dyn Session + 'a>, ParseError>;
+
+/// The entry points of one day, with the `Solution` type erased so that
+/// days can be picked at runtime.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub solve: Solver,
+    pub bench: Bencher,
+    /// A random input of about the given size, see `dXX::generate`.
+    pub generate: fn(&mut StdRng, usize) -> String,
+    /// The frames `aoc viz` draws, for the days that can be visualized.
+    pub frames: Option<Frames>,
+    /// The image `aoc image` saves, for the days that can be pictured.
+    pub image: Option<Picturer>,
+    /// The session `aoc repl` starts, for the days that can be explored.
+    pub session: Option<Starter>,
+}
+
+impl Day {
+    fn of<S: Solution>(generate: fn(&mut StdRng, usize) -> String) -> Self {
+        Self {
+            number: S::DAY,
+            solve: solve::<S>,
+            bench: bench::time::<S>,
+            generate,
+            frames: None,
+            image: None,
+            session: None,
+        }
+    }
+
+    fn with_frames<S: Visualize>(self) -> Self {
+        Self {
+            frames: Some(frames::<S>),
+            ..self
+        }
+    }
+
+    fn with_image<S: Picture>(self) -> Self {
+        Self {
+            image: Some(image::<S>),
+            ..self
+        }
+    }
+
+    fn with_session<S: Explore>(self) -> Self {
+        Self {
+            session: Some(session::<S>),
+            ..self
+        }
+    }
+}
+
+pub fn all() -> [Day; 18] {
+    [
+        Day::of::<d01::Day01>(d01::generate::input),
+        Day::of::<d02::Day02>(d02::generate::input),
+        Day::of::<d03::Day03>(d03::generate::input),
+        Day::of::<d04::Day04>(d04::generate::input),
+        Day::of::<d05::Day05>(d05::generate::input).with_image::<d05::Day05>(),
+        Day::of::<d06::Day06>(d06::generate::input).with_session::<d06::Day06>(),
+        Day::of::<d07::Day07>(d07::generate::input),
+        Day::of::<d08::Day08>(d08::generate::input),
+        Day::of::<d09::Day09>(d09::generate::input)
+            .with_frames::<d09::Day09>()
+            .with_image::<d09::Day09>(),
+        Day::of::<d10::Day10>(d10::generate::input),
+        Day::of::<d11::Day11>(d11::generate::input)
+            .with_frames::<d11::Day11>()
+            .with_session::<d11::Day11>(),
+        Day::of::<d12::Day12>(d12::generate::input),
+        Day::of::<d13::Day13>(d13::generate::input)
+            .with_frames::<d13::Day13>()
+            .with_image::<d13::Day13>()
+            .with_session::<d13::Day13>(),
+        Day::of::<d14::Day14>(d14::generate::input),
+        Day::of::<d15::Day15>(d15::generate::input)
+            .with_frames::<d15::Day15>()
+            .with_image::<d15::Day15>(),
+        Day::of::<d16::Day16>(d16::generate::input).with_session::<d16::Day16>(),
+        Day::of::<d17::Day17>(d17::generate::input),
+        Day::of::<d18::Day18>(d18::generate::input).with_session::<d18::Day18>(),
+    ]
+}
+
+/// Picks the input for `day`. `--input` wins over `--profile`, which wins
+/// over `AOC_PROFILE`, and without any of them `dXX/input.txt` is read.
+pub fn source(day: u8, input: Option<&str>, profile: Option<&str>) -> InputSource {
+    match (input, profile) {
+        (Some(input), _) => InputSource::from_arg(input),
+        (None, Some(profile)) => InputSource::profile(profile),
+        (None, None) => InputSource::from_env()
+            .unwrap_or_else(|| InputSource::Path(format!("d{:02}/input.txt", day).into())),
+    }
+}
+
+pub fn get(day: u8) -> Result<Day, Box<dyn Error>> {
+    all()
+        .into_iter()
+        .find(|d| d.number == day)
+        .ok_or_else(|| format!("day {} is not solved", day).into())
+}
+
+/// Formats an answer the way `run` prints it, multi-line answers start on
+/// their own line.
+pub fn render(part: u8, answer: &str) -> String {
+    match answer.contains('\n') {
+        true => format!("Part {}:\n{}", part, answer),
+        false => format!("Part {}: {}", part, answer),
+    }
+}
+
+/// One solved part and how long solving it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: u8,
+    pub answer: String,
+    pub time: Duration,
+}
+
+fn answer<T: Display>(
+    part: u8,
+    only: Option<u8>,
+    f: impl FnOnce() -> Result<T, Box<dyn Error>>,
+) -> Option<Result<Answer, Box<dyn Error>>> {
+    only.is_none_or(|p| p == part).then(|| {
+        let start = Instant::now();
+        let answer = f()?.to_string();
+        Ok(Answer {
+            part,
+            answer,
+            time: start.elapsed(),
+        })
+    })
+}
+
+fn solve<S: Solution>(input: &str, only: Option<u8>) -> Result<Vec<Answer>, Box<dyn Error>> {
+    let data = S::parse(input)?;
+    [
+        answer(1, only, || S::part1(&data)),
+        answer(2, only, || S::part2(&data)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn frames<S: Visualize>(input: &str) -> Result<Vec<Frame>, Box<dyn Error>> {
+    S::frames(&S::parse(input)?)
+}
+
+fn image<S: Picture>(input: &str) -> Result<Image, Box<dyn Error>> {
+    S::image(&S::parse(input)?)
+}
+
+fn session<S: Explore>(input: &str) -> Result<Box<dyn Session + '_>, ParseError> {
+    Ok(S::session(S::parse(input)?))
+}
+
+/// Solves each of `days`, or every day when it is empty.
+pub fn run(
+    days: &[u8],
+    input: Option<&str>,
+    profile: Option<&str>,
+    only: Option<u8>,
+    format: Format,
+    trace: bool,
+) -> Result<(), Box<dyn Error>> {
+    let days = match days.is_empty() {
+        true => all().map(|d| d.number).to_vec(),
+        false => days.to_vec(),
+    };
+    if input.is_some() && days.len() != 1 {
+        return Err("`--input` needs exactly one day".into());
+    }
+    crate::trace::check(trace)?;
+    let mut report = Report::new(format, days.len() > 1);
+    for day in days {
+        let source = source(day, input, profile);
+        let text = source.read(day)?;
+        let (answers, summary) = match trace {
+            true => crate::trace::solve(get(day)?, &text, only),
+            false => ((get(day)?.solve)(&text, only), String::new()),
+        };
+        report.add(day, source.name(day), answers?);
+        eprint!("{}", summary);
+    }
+    Ok(report.finish()?)
+}
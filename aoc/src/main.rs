@@ -0,0 +1,232 @@
+use std::{error::Error, path::PathBuf, process::ExitCode, time::Duration};
+
+use clap::{Parser, Subcommand};
+use report::Format;
+
+mod bench;
+mod days;
+mod registry;
+mod report;
+mod repl;
+mod stress;
+mod trace;
+mod viz;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run some days, or all of them
+    Run {
+        /// The days to run, from 1 to 18. Runs every day when left out
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=18))]
+        days: Vec<u8>,
+        /// Only run this part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// The input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<String>,
+        /// Read `inputs/<profile>/dXX.txt`, defaults to `$AOC_PROFILE`
+        #[arg(long, conflicts_with = "input")]
+        profile: Option<String>,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Print the spans and counters of the solvers to stderr, needs the
+        /// `trace` feature
+        #[arg(long)]
+        trace: bool,
+    },
+    /// Time parse, part 1 and part 2 of every day on its example and real input
+    Bench {
+        /// Only benchmark this day
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=18))]
+        day: Option<u8>,
+        /// How many times to run each stage, the fastest run is reported
+        #[arg(long, default_value_t = 10)]
+        runs: u32,
+        /// Use `inputs/<profile>/dXX.txt` as the real input, defaults to `$AOC_PROFILE`
+        #[arg(long)]
+        profile: Option<String>,
+        /// Save the results as a baseline for later runs
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare against a baseline written with `--save`
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+    /// Check every registered input against its expected answers
+    Test {
+        /// Only check this day
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=18))]
+        day: Option<u8>,
+        /// The registry, holding `<profile>/dXX.txt` and `<profile>/dXX.answers`
+        #[arg(long, default_value = "inputs")]
+        inputs: PathBuf,
+    },
+    /// Print a random input for a day
+    Generate {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=18))]
+        day: u8,
+        /// The same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Roughly how many lines, packets or cells the input has
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Solve many generated inputs, reporting the seeds that fail or panic
+    Stress {
+        /// Only stress this day
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=18))]
+        day: Option<u8>,
+        /// The first seed, the following runs use the seeds after it
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How many inputs to solve per day
+        #[arg(long, default_value_t = 100)]
+        runs: u64,
+        /// Roughly how many lines, packets or cells each input has. Day 12
+        /// counts paths, which grow quickly past 30 passages
+        #[arg(long, default_value_t = 30)]
+        size: usize,
+    },
+    /// Animate how a day is solved, for days 9, 11, 13 and 15
+    Viz {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=18))]
+        day: u8,
+        /// The input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<String>,
+        /// Read `inputs/<profile>/dXX.txt`, defaults to `$AOC_PROFILE`
+        #[arg(long, conflicts_with = "input")]
+        profile: Option<String>,
+        /// Milliseconds between frames
+        #[arg(long, default_value_t = 100)]
+        delay: u64,
+        /// Write the frames as text files to this directory instead
+        #[arg(long)]
+        dump: Option<PathBuf>,
+        /// Leave out the ANSI colours
+        #[arg(long)]
+        no_colour: bool,
+    },
+    /// Load an input and change it with commands, for days 6, 11, 13, 16 and 18
+    Repl {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=18))]
+        day: u8,
+        /// The input file
+        #[arg(long)]
+        input: Option<String>,
+        /// Read `inputs/<profile>/dXX.txt`, defaults to `$AOC_PROFILE`
+        #[arg(long, conflicts_with = "input")]
+        profile: Option<String>,
+    },
+    /// Save a day as a PGM or PPM image, for days 5, 9, 13 and 15
+    Image {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=18))]
+        day: u8,
+        /// The input file, or `-` for stdin
+        #[arg(long)]
+        input: Option<String>,
+        /// Read `inputs/<profile>/dXX.txt`, defaults to `$AOC_PROFILE`
+        #[arg(long, conflicts_with = "input")]
+        profile: Option<String>,
+        /// Draw every cell as a square of this many pixels
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+        scale: u16,
+        /// Where to save the image, defaults to `dXX.pgm` or `dXX.ppm`
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            input,
+            profile,
+            format,
+            trace,
+        } => days::run(
+            &days,
+            input.as_deref(),
+            profile.as_deref(),
+            part,
+            format,
+            trace,
+        ),
+        Command::Bench {
+            day,
+            runs,
+            profile,
+            save,
+            baseline,
+        } => bench::run(
+            day,
+            runs,
+            profile.as_deref(),
+            save.as_deref(),
+            baseline.as_deref(),
+        ),
+        Command::Test { day, inputs } => registry::run(&inputs, day),
+        Command::Generate { day, seed, size } => stress::generate(day, seed, size),
+        Command::Stress {
+            day,
+            seed,
+            runs,
+            size,
+        } => stress::run(day, seed, runs, size),
+        Command::Viz {
+            day,
+            input,
+            profile,
+            delay,
+            dump,
+            no_colour,
+        } => viz::run(
+            day,
+            input.as_deref(),
+            profile.as_deref(),
+            Duration::from_millis(delay),
+            dump.as_deref(),
+            !no_colour,
+        ),
+        Command::Repl {
+            day,
+            input,
+            profile,
+        } => repl::run(day, input.as_deref(), profile.as_deref()),
+        Command::Image {
+            day,
+            input,
+            profile,
+            scale,
+            out,
+        } => viz::image(
+            day,
+            input.as_deref(),
+            profile.as_deref(),
+            scale.into(),
+            out.as_deref(),
+        ),
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
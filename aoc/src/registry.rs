@@ -0,0 +1,181 @@
+use std::{
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::days::{self, render};
+
+/// A registered input, `<root>/<profile>/dXX.txt`, with its expected answers
+/// next to it in `dXX.answers`.
+///
+/// The answers file holds the output of `aoc run` for that input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u8,
+    pub profile: String,
+    pub input: PathBuf,
+    pub answers: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Ok,
+    Changed(Vec<(u8, String, String)>),
+    Failed(String),
+}
+
+fn day_of(file_name: &str) -> Option<u8> {
+    file_name
+        .strip_prefix('d')?
+        .strip_suffix(".txt")
+        .filter(|n| n.len() == 2)?
+        .parse()
+        .ok()
+}
+
+/// Every input under `root` that has an answers file, ordered by day and profile.
+pub fn entries(root: &Path) -> io::Result<Vec<Entry>> {
+    let mut entries = Vec::new();
+    for profile in fs::read_dir(root)? {
+        let profile = profile?;
+        if !profile.file_type()?.is_dir() {
+            continue;
+        }
+        for file in fs::read_dir(profile.path())? {
+            let input = file?.path();
+            let Some(day) = input.file_name().and_then(|n| n.to_str()).and_then(day_of) else {
+                continue;
+            };
+            let answers = input.with_extension("answers");
+            if answers.is_file() {
+                entries.push(Entry {
+                    day,
+                    profile: profile.file_name().to_string_lossy().into_owned(),
+                    input,
+                    answers,
+                });
+            }
+        }
+    }
+    entries.sort_by(|a, b| (a.day, &a.profile).cmp(&(b.day, &b.profile)));
+    Ok(entries)
+}
+
+fn parse_answers(s: &str) -> Option<[String; 2]> {
+    let (part1, part2) = s.trim_end().strip_prefix("Part 1:")?.split_once("\nPart 2:")?;
+    Some([part1, part2].map(|a| a.strip_prefix([' ', '\n']).unwrap_or(a).to_string()))
+}
+
+pub fn check(entry: &Entry) -> Result<Outcome, Box<dyn Error>> {
+    let expected = parse_answers(&fs::read_to_string(&entry.answers)?)
+        .ok_or_else(|| format!("{}: expected `Part 1:` and `Part 2:`", entry.answers.display()))?;
+    let input = fs::read_to_string(&entry.input)?;
+    let actual = match (days::get(entry.day)?.solve)(&input, None) {
+        Ok(actual) => actual.into_iter().map(|a| a.answer),
+        Err(e) => return Ok(Outcome::Failed(e.to_string())),
+    };
+    let changed: Vec<_> = (1..=2)
+        .zip(expected.into_iter().zip(actual))
+        .filter(|(_, (e, a))| e != a)
+        .map(|(part, (e, a))| (part, e, a))
+        .collect();
+    match changed.is_empty() {
+        true => Ok(Outcome::Ok),
+        false => Ok(Outcome::Changed(changed)),
+    }
+}
+
+/// Runs every registered input and reports the answers that changed.
+pub fn run(root: &Path, day: Option<u8>) -> Result<(), Box<dyn Error>> {
+    let entries = entries(root).map_err(|e| format!("{}: {}", root.display(), e))?;
+    let mut failures = 0;
+    let mut checked = 0;
+    for entry in entries.iter().filter(|e| day.is_none_or(|d| d == e.day)) {
+        checked += 1;
+        let label = format!("d{:02} {}", entry.day, entry.profile);
+        // a broken entry fails on its own, the others are still checked
+        match check(entry).unwrap_or_else(|e| Outcome::Failed(e.to_string())) {
+            Outcome::Ok => println!("{:<20} ok", label),
+            Outcome::Changed(parts) => {
+                failures += 1;
+                println!("{:<20} changed", label);
+                for (part, expected, actual) in parts {
+                    println!("  expected {}", render(part, &expected));
+                    println!("  found    {}", render(part, &actual));
+                }
+            }
+            Outcome::Failed(e) => {
+                failures += 1;
+                println!("{:<20} failed: {}", label, e);
+            }
+        }
+    }
+    println!("{} inputs checked, {} changed or failed", checked, failures);
+    match failures {
+        0 => Ok(()),
+        n => Err(format!("{} registered answers do not match", n).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs")
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("Part 1: 17\nPart 2:\n##\n #\n"),
+            Some(["17".to_string(), "##\n #".to_string()])
+        );
+        assert_eq!(parse_answers("Part 1: 17\n"), None);
+        assert_eq!(day_of("d07.txt"), Some(7));
+        assert_eq!(day_of("d7.txt"), None);
+    }
+
+    #[test]
+    fn test_registered_answers() {
+        let entries = entries(&root()).unwrap();
+        assert!(entries.iter().any(|e| e.profile == "anthony"));
+        for entry in entries {
+            assert_eq!(check(&entry).unwrap(), Outcome::Ok, "{:?}", entry);
+        }
+    }
+
+    #[test]
+    fn test_run_error_fails() {
+        let dir = std::env::temp_dir().join(format!("aoc-registry-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let entry = Entry {
+            day: 2,
+            profile: "surfacing".to_string(),
+            input: dir.join("d02.txt"),
+            answers: dir.join("d02.answers"),
+        };
+        fs::write(&entry.input, "up 3\n").unwrap();
+        fs::write(&entry.answers, "Part 1: 0\nPart 2: 0\n").unwrap();
+        assert_eq!(
+            check(&entry).unwrap(),
+            Outcome::Failed("command 1 (`up 3`) takes the submarine above the surface".to_string())
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_broken_entries() {
+        let dir = std::env::temp_dir().join(format!("aoc-registry-broken-{}", std::process::id()));
+        let profile = dir.join("broken");
+        fs::create_dir_all(&profile).unwrap();
+        fs::write(profile.join("d01.txt"), "1\n2\n").unwrap();
+        fs::write(profile.join("d01.answers"), "no answers\n").unwrap();
+        fs::write(profile.join("d07.txt"), "16,1,2\n").unwrap();
+        fs::write(profile.join("d07.answers"), "Part 1: 0\nPart 2: 0\n").unwrap();
+        let e = run(&dir, None).unwrap_err();
+        assert_eq!(e.to_string(), "2 registered answers do not match");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    error::Error,
+    io::{self, BufRead, Write},
+};
+
+use crate::days::{self, Starter};
+
+/// The commands every day has, listed by `help` below the day's own.
+const COMMON: &str = "\
+show: print the state again
+reset: start over from the input
+help: list the commands
+quit: leave";
+
+/// Explores `day` with commands read from stdin.
+pub fn run(day: u8, input: Option<&str>, profile: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let start = days::get(day)?
+        .session
+        .ok_or_else(|| format!("day {} has no repl", day))?;
+    let text = days::source(day, input, profile).read(day)?;
+    let prompt = format!("d{:02}> ", day);
+    interact(start, &text, io::stdin().lock(), io::stdout().lock(), &prompt)
+}
+
+/// Runs one command per line of `commands`, printing the state after each.
+fn interact<R: BufRead, W: Write>(
+    start: Starter,
+    text: &str,
+    commands: R,
+    mut out: W,
+    prompt: &str,
+) -> Result<(), Box<dyn Error>> {
+    let mut session = start(text)?;
+    writeln!(out, "{}", session.state())?;
+    write!(out, "{}", prompt)?;
+    out.flush()?;
+    for line in commands.lines() {
+        let line = line?;
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        match words.split_first() {
+            None => {}
+            Some((&("quit" | "exit"), _)) => break,
+            Some((&"help", _)) => writeln!(out, "{}\n{}", session.help(), COMMON)?,
+            Some((&"show", _)) => writeln!(out, "{}", session.state())?,
+            Some((&"reset", _)) => {
+                session = start(text)?;
+                writeln!(out, "{}", session.state())?;
+            }
+            Some((command, args)) => match session.run(command, args) {
+                Ok(message) => {
+                    if let Some(message) = message {
+                        writeln!(out, "{}", message)?;
+                    }
+                    writeln!(out, "{}", session.state())?;
+                }
+                Err(e) => writeln!(out, "error: {}", e)?,
+            },
+        }
+        write!(out, "{}", prompt)?;
+        out.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transcript(day: u8, text: &str, commands: &str) -> String {
+        let start = days::get(day).unwrap().session.unwrap();
+        let mut out = Vec::new();
+        interact(start, text, commands.as_bytes(), &mut out, "> ").unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_step_fish() {
+        let out = transcript(6, "3,4,3,1,2\n", "step 18\nstep x\nreset\nquit\nstep\n");
+        assert_eq!(
+            out.lines().filter(|l| l.contains("fish")).collect::<Vec<_>>(),
+            ["day 0: 5 fish", "> day 18: 26 fish", "> day 0: 5 fish"]
+        );
+        assert!(out.contains("error: expected a number of times, found `x`"));
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let out = transcript(6, "3,4,3,1,2\n", "step 1000\n");
+        assert!(out.contains("error: after day 4"));
+        assert!(out.contains("there are too many fish to count\n"));
+        let out = transcript(13, "6,10\n0,14\n\nfold along y=7\n", "fold x=1\nfold y=7\n");
+        assert!(out.contains("error: folding along x=1 would take the dots at x=6 past the edge"));
+        assert!(out.contains("2 dots, 0 of 1 folds made"));
+        let out = transcript(13, "0,100000000\n\nfold along y=50000000\n", "fold\n");
+        assert!(out.contains("the sheet is 1x100000001, too big to draw, fold it to see it"));
+        assert!(out.contains("1 dots, 1 of 1 folds made\n#"));
+    }
+
+    #[test]
+    fn test_snailfish_and_packets() {
+        let out = transcript(18, "[1,2]\n", "add [[3,4],5]\nadd [1,\n");
+        assert!(out.contains("[[1,2],[[3,4],5]]\nmagnitude 143\n"));
+        assert!(out.contains("error: day 18, line 1"));
+        let out = transcript(16, "D2FE28\n", "decode 38006F45291200\nfrob\n");
+        assert!(out.contains("v1 less than = 1\n  v6 literal 10\n  v2 literal 20\n"));
+        assert!(out.contains("error: unknown command `frob`"));
+    }
+}
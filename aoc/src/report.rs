@@ -0,0 +1,92 @@
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::days::{render, Answer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// `Part 1: <answer>` lines, as the day binaries print them
+    Text,
+    /// A JSON array with one object per day and part
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: String,
+    time_ns: u64,
+}
+
+/// Collects the answers of one `aoc run` and prints them in `format`.
+///
+/// Text is printed as each day finishes, JSON once everything is solved.
+#[derive(Debug)]
+pub struct Report {
+    format: Format,
+    headers: bool,
+    records: Vec<Record>,
+}
+
+impl Report {
+    /// `headers` prints the day above its answers in text, for runs of
+    /// several days.
+    pub fn new(format: Format, headers: bool) -> Self {
+        Self {
+            format,
+            headers,
+            records: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, day: u8, input: String, answers: Vec<Answer>) {
+        match self.format {
+            Format::Text => {
+                if self.headers {
+                    println!("Day {} ({})", day, input);
+                }
+                for a in answers {
+                    println!("{}", render(a.part, &a.answer));
+                }
+            }
+            Format::Json => self.records.extend(answers.into_iter().map(|a| Record {
+                day,
+                part: a.part,
+                input: input.clone(),
+                answer: a.answer,
+                time_ns: a.time.as_nanos() as u64,
+            })),
+        }
+    }
+
+    pub fn finish(self) -> serde_json::Result<()> {
+        if self.format == Format::Json {
+            println!("{}", serde_json::to_string_pretty(&self.records)?);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_json_record() {
+        let mut report = Report::new(Format::Json, false);
+        let answer = Answer {
+            part: 2,
+            answer: "168".to_string(),
+            time: Duration::from_nanos(1500),
+        };
+        report.add(7, "inputs/example/d07.txt".to_string(), vec![answer]);
+        assert_eq!(
+            serde_json::to_string(&report.records).unwrap(),
+            r#"[{"day":7,"part":2,"input":"inputs/example/d07.txt","answer":"168","time_ns":1500}]"#
+        );
+    }
+}
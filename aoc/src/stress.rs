@@ -0,0 +1,125 @@
+use std::{
+    error::Error,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{
+    bench::format_duration,
+    days::{self, Day},
+};
+
+/// Prints the input `day` generates from `seed`.
+pub fn generate(day: u8, seed: u64, size: usize) -> Result<(), Box<dyn Error>> {
+    let day = days::get(day)?;
+    print!("{}", (day.generate)(&mut StdRng::seed_from_u64(seed), size));
+    Ok(())
+}
+
+/// What went wrong solving one generated input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub seed: u64,
+    pub message: String,
+}
+
+/// The result of solving the inputs of `runs` seeds from `seed` on.
+#[derive(Debug, Clone, Default)]
+pub struct Summary {
+    pub failures: Vec<Failure>,
+    pub slowest: Option<(u64, Duration)>,
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(s), _) => s.to_string(),
+        (_, Some(s)) => s.clone(),
+        _ => "panicked".to_string(),
+    }
+}
+
+/// Solves generated inputs of `day`, catching parse errors and panics. The
+/// seeds stop at the last one.
+pub fn stress(day: Day, seed: u64, runs: u64, size: usize) -> Summary {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let mut summary = Summary::default();
+    for seed in seed..seed.saturating_add(runs) {
+        let input = (day.generate)(&mut StdRng::seed_from_u64(seed), size);
+        let start = Instant::now();
+        let message = match panic::catch_unwind(AssertUnwindSafe(|| (day.solve)(&input, None))) {
+            Ok(Ok(_)) => None,
+            Ok(Err(e)) => Some(e.to_string()),
+            Err(payload) => Some(format!("panicked: {}", panic_message(&*payload))),
+        };
+        let time = start.elapsed();
+        match message {
+            Some(message) => summary.failures.push(Failure { seed, message }),
+            None if summary.slowest.is_none_or(|(_, t)| t < time) => {
+                summary.slowest = Some((seed, time))
+            }
+            None => {}
+        }
+    }
+    panic::set_hook(hook);
+    summary
+}
+
+/// Stresses one day, or every day, and lists the seeds that failed.
+pub fn run(day: Option<u8>, seed: u64, runs: u64, size: usize) -> Result<(), Box<dyn Error>> {
+    if seed.checked_add(runs).is_none() {
+        return Err(format!("{} runs from seed {} go past the last seed", runs, seed).into());
+    }
+    let days = match day {
+        Some(day) => vec![days::get(day)?],
+        None => days::all().to_vec(),
+    };
+    let mut failures = 0;
+    for day in days {
+        let summary = stress(day, seed, runs, size);
+        let slowest = match summary.slowest {
+            Some((seed, time)) => format!("slowest {} (seed {})", format_duration(time), seed),
+            None => "no input solved".to_string(),
+        };
+        println!(
+            "d{:02} {}/{} ok, {}",
+            day.number,
+            runs as usize - summary.failures.len(),
+            runs,
+            slowest
+        );
+        for f in &summary.failures {
+            println!("  seed {}: {}", f.seed, f.message);
+        }
+        failures += summary.failures.len();
+    }
+    match failures {
+        0 => Ok(()),
+        n => Err(format!("{} generated inputs failed", n).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for day in days::all() {
+            let summary = stress(day, 0, 20, 10);
+            assert_eq!(summary.failures, vec![], "day {}", day.number);
+        }
+        // ties for the last board to win need many boards
+        let summary = stress(days::get(4).unwrap(), 0, 40, 30);
+        assert_eq!(summary.failures, vec![]);
+    }
+
+    #[test]
+    fn test_last_seed() {
+        let summary = stress(days::get(1).unwrap(), u64::MAX - 1, 5, 10);
+        assert_eq!(summary.failures, vec![]);
+        assert!(run(Some(1), u64::MAX - 1, 5, 10).is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use std::error::Error;
+
+use crate::days::{Answer, Day};
+
+/// Fails for `--trace` when aoc is built without the `trace` feature.
+pub fn check(trace: bool) -> Result<(), Box<dyn Error>> {
+    match trace && cfg!(not(feature = "trace")) {
+        true => Err("`--trace` needs aoc built with `--features trace`".into()),
+        false => Ok(()),
+    }
+}
+
+/// Solves `day` like `Day::solve`, with a summary of where the time went.
+#[cfg(feature = "trace")]
+pub fn solve(day: Day, input: &str, only: Option<u8>) -> (Result<Vec<Answer>, Box<dyn Error>>, String) {
+    let (answers, stats) = aoc_common::trace::record(|| (day.solve)(input, only));
+    (answers, summary(day.number, &stats))
+}
+
+#[cfg(not(feature = "trace"))]
+pub fn solve(day: Day, input: &str, only: Option<u8>) -> (Result<Vec<Answer>, Box<dyn Error>>, String) {
+    ((day.solve)(input, only), String::new())
+}
+
+/// One line per span and counter, the spans with their calls and time.
+#[cfg(feature = "trace")]
+fn summary(day: u8, stats: &aoc_common::trace::Stats) -> String {
+    use std::fmt::Write;
+
+    use crate::bench::format_duration;
+
+    let mut s = format!("Day {} trace\n", day);
+    if stats.spans.is_empty() && stats.counters.is_empty() {
+        s.push_str("  nothing is instrumented\n");
+    }
+    for span in &stats.spans {
+        let _ = writeln!(
+            s,
+            "  {:<28} {:>10} calls {:>10}",
+            span.name,
+            span.calls,
+            format_duration(span.busy)
+        );
+    }
+    for counter in &stats.counters {
+        let _ = writeln!(
+            s,
+            "  {:<28} {:>10} total over {} events, at most {}",
+            counter.name, counter.total, counter.events, counter.max
+        );
+    }
+    s
+}
+
+#[cfg(all(test, feature = "trace"))]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn test_summary() {
+        let day = days::get(15).unwrap();
+        let (_, stats) = aoc_common::trace::record(|| (day.solve)("19\n11\n", Some(1)));
+        let summary = summary(15, &stats);
+        assert!(summary.starts_with("Day 15 trace\n  d15 lowest_risks"));
+        assert!(summary.contains("d15 sweeps                            2 total over 1 events, at most 2"));
+    }
+}
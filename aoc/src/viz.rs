@@ -0,0 +1,65 @@
+use std::{
+    error::Error,
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_common::{Canvas, FrameDump, Terminal};
+
+use crate::days;
+
+/// Draws the frames of `day` on the terminal, or into `dump` when given.
+pub fn run(
+    day: u8,
+    input: Option<&str>,
+    profile: Option<&str>,
+    delay: Duration,
+    dump: Option<&Path>,
+    colour: bool,
+) -> Result<(), Box<dyn Error>> {
+    let frames = days::get(day)?
+        .frames
+        .ok_or_else(|| format!("day {} has no visualization", day))?;
+    let source = days::source(day, input, profile);
+    let frames = frames(&source.read(day)?)?;
+    let mut canvas: Box<dyn Canvas> = match dump {
+        Some(dir) => Box::new(FrameDump::new(dir)?),
+        None => Box::new(Terminal::new(io::stdout().lock(), delay, colour)),
+    };
+    for frame in &frames {
+        canvas.draw(frame)?;
+    }
+    if let Some(dir) = dump {
+        println!("wrote {} frames to {}", frames.len(), dir.display());
+    }
+    Ok(())
+}
+
+/// Saves the image of `day` to `out`, or `dXX.pgm` or `dXX.ppm` when left
+/// out.
+pub fn image(
+    day: u8,
+    input: Option<&str>,
+    profile: Option<&str>,
+    scale: usize,
+    out: Option<&Path>,
+) -> Result<(), Box<dyn Error>> {
+    let image = days::get(day)?
+        .image
+        .ok_or_else(|| format!("day {} has no image", day))?;
+    let source = days::source(day, input, profile);
+    let image = image(&source.read(day)?)?;
+    let out = match out {
+        Some(out) => out.to_path_buf(),
+        None => PathBuf::from(format!("d{:02}.{}", day, image.extension())),
+    };
+    image.save(&out, scale)?;
+    println!(
+        "wrote a {}x{} image to {}",
+        image.width() * scale,
+        image.height() * scale,
+        out.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// A malformed puzzle input, pointing at the offending spot.
+///
+/// Lines and columns are 1-based, and columns count characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub description: String,
+}
+
+impl ParseError {
+    pub fn new<S: Into<String>>(day: u8, line: usize, column: usize, description: S) -> Self {
+        Self {
+            day,
+            line,
+            column,
+            description: description.into(),
+        }
+    }
+
+    /// An error at `token`, which must be a slice of `text`.
+    ///
+    /// The line and column are counted from the start of `text`. If `token`
+    /// is not inside `text` the error points just past its end.
+    pub fn at<S: Into<String>>(day: u8, text: &str, token: &str, description: S) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|o| *o <= text.len())
+            .unwrap_or(text.len());
+        let before = &text[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit_once('\n')
+            .map_or(before, |(_, l)| l)
+            .chars()
+            .count()
+            + 1;
+        Self::new(day, line, column, description)
+    }
+
+    /// Moves an error reported against a single line to `line` of the full input.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line += line - 1;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.description
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `text`, reporting a failure at its position.
+pub fn parse_at<T>(day: u8, text: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token
+        .parse()
+        .map_err(|e| ParseError::at(day, text, token, format!("invalid number `{}`: {}", token, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let text = "0,9 -> 5,9\n8,0 -> 0,x\n";
+        let e = ParseError::at(5, text, &text[20..21], "bad");
+        assert_eq!(e, ParseError::new(5, 2, 10, "bad"));
+        assert_eq!(e.to_string(), "day 5, line 2, column 10: bad");
+    }
+
+    #[test]
+    fn test_outside() {
+        let text = "12\n34";
+        let e = ParseError::at(1, text, "other", "bad");
+        assert_eq!((e.line, e.column), (2, 3));
+        assert_eq!(e.on_line(4).line, 5);
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let text = "1,2,x";
+        assert_eq!(parse_at::<usize>(7, text, &text[2..3]), Ok(2));
+        let e = parse_at::<usize>(7, text, &text[4..]).unwrap_err();
+        assert_eq!((e.line, e.column), (1, 5));
+    }
+}
@@ -0,0 +1,281 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::{parse_digit_row, parse_lines_with, ParseError};
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const ADJACENT: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row, indexed with `(x, y)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from rows, returning `None` if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|r| r.len() != width) {
+            return None;
+        }
+        Some(Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self {
+            width,
+            height,
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(|(x, y)| f(x, y))
+                .collect(),
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        match self.contains((x, y)) {
+            true => self.cells.get(y * self.width + x),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        match self.contains((x, y)) {
+            true => self.cells.get_mut(y * self.width + x),
+            false => None,
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> slice::Chunks<'_, T> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// The up to four positions that share an edge with `(x, y)`.
+    pub fn neighbours4(&self, x: usize, y: usize) -> Neighbours {
+        Neighbours::new(self, x, y, &ORTHOGONAL)
+    }
+
+    /// The up to eight positions that share an edge or a corner with `(x, y)`.
+    pub fn neighbours8(&self, x: usize, y: usize) -> Neighbours {
+        Neighbours::new(self, x, y, &ADJACENT)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<usize> {
+    /// Parses lines of single digits, like `2199943210`.
+    pub fn from_digits(day: u8, input: &str) -> Result<Self, ParseError> {
+        let mut width = None;
+        let rows = parse_lines_with(input, |l| {
+            let row = parse_digit_row(day, l)?;
+            match *width.get_or_insert(row.len()) {
+                w if w == row.len() => Ok(row),
+                w => Err(ParseError::at(
+                    day,
+                    l,
+                    &l[l.len()..],
+                    format!("expected {} digits, found {}", w, row.len()),
+                )),
+            }
+        })?;
+        match rows.is_empty() {
+            true => Err(ParseError::new(day, 1, 1, "expected a grid of digits")),
+            false => Ok(Self::from_rows(rows).expect("rows have the same width")),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(x < self.width, "x: {} is outside the grid", x);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(x < self.width, "x: {} is outside the grid", x);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the in-bounds neighbours of a position. It does not borrow the grid.
+#[derive(Debug, Clone)]
+pub struct Neighbours {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    deltas: slice::Iter<'static, (isize, isize)>,
+}
+
+impl Neighbours {
+    fn new<T>(grid: &Grid<T>, x: usize, y: usize, deltas: &'static [(isize, isize)]) -> Self {
+        Self {
+            x,
+            y,
+            width: grid.width,
+            height: grid.height,
+            deltas: deltas.iter(),
+        }
+    }
+}
+
+impl Iterator for Neighbours {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (dx, dy) in self.deltas.by_ref() {
+            match (self.x.checked_add_signed(*dx), self.y.checked_add_signed(*dy)) {
+                (Some(x), Some(y)) if x < self.width && y < self.height => return Some((x, y)),
+                _ => continue,
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2199943210
+3987894921
+9856789892
+";
+
+    #[test]
+    fn test_parse_and_print() {
+        let grid = Grid::from_digits(9, EXAMPLE).unwrap();
+        assert_eq!((grid.width(), grid.height()), (10, 3));
+        assert_eq!(grid[(1, 2)], 8);
+        assert_eq!(grid.row(1)[9], 1);
+        assert_eq!(grid.column(0).copied().collect::<Vec<_>>(), [2, 3, 9]);
+        assert_eq!(format!("{}\n", grid), EXAMPLE);
+    }
+
+    #[test]
+    fn test_ragged() {
+        assert_eq!(
+            Grid::from_digits(9, "123\n45\n"),
+            Err(ParseError::new(9, 2, 3, "expected 3 digits, found 2"))
+        );
+        assert_eq!(
+            Grid::from_digits(9, "12a\n"),
+            Err(ParseError::new(9, 1, 3, "expected a digit, found `a`"))
+        );
+        assert!(Grid::from_digits(9, "\n").is_err());
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::filled(10, 3, 0);
+        assert_eq!(grid.neighbours4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4(5, 1).count(), 4);
+        assert_eq!(grid.neighbours4(9, 2).count(), 2);
+        assert_eq!(grid.neighbours8(0, 0).count(), 3);
+        assert_eq!(grid.neighbours8(0, 1).count(), 5);
+        assert_eq!(grid.neighbours8(5, 1).count(), 8);
+        assert_eq!(grid.neighbours8(9, 2).count(), 3);
+        assert!(grid.get((10, 0)).is_none());
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    error::Error,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    slice,
+};
+
+use crate::{Grid, Solution};
+
+/// The red, green and blue of one pixel.
+pub type Rgb = [u8; 3];
+
+/// A picture that is written as a binary PGM or PPM file, without any image
+/// library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Image {
+    /// Shades of grey from black at 0 to white at 255, written as PGM.
+    Grey(Grid<u8>),
+    /// Written as PPM.
+    Rgb(Grid<Rgb>),
+}
+
+impl Image {
+    /// Black dots on white, from the origin to the furthest point.
+    pub fn points<I: IntoIterator<Item = (usize, usize)>>(points: I) -> Self {
+        let points = points.into_iter().collect::<Vec<_>>();
+        let width = points.iter().map(|p| p.0 + 1).max().unwrap_or(0);
+        let height = points.iter().map(|p| p.1 + 1).max().unwrap_or(0);
+        let mut grid = Grid::filled(width, height, 255);
+        for p in points {
+            grid[p] = 0;
+        }
+        Image::Grey(grid)
+    }
+
+    /// Black at 0 up to white at the highest value.
+    pub fn heat(grid: &Grid<usize>) -> Self {
+        let max = grid.values().copied().max().unwrap_or(0).max(1);
+        Image::Grey(grid.map(|&v| (v * 255 / max) as u8))
+    }
+
+    pub fn width(&self) -> usize {
+        match self {
+            Image::Grey(g) => g.width(),
+            Image::Rgb(g) => g.width(),
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        match self {
+            Image::Grey(g) => g.height(),
+            Image::Rgb(g) => g.height(),
+        }
+    }
+
+    /// `pgm` or `ppm`.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Image::Grey(_) => "pgm",
+            Image::Rgb(_) => "ppm",
+        }
+    }
+
+    /// Writes the image with every pixel blown up to a `scale` by `scale`
+    /// square.
+    pub fn write<W: Write>(&self, mut out: W, scale: usize) -> io::Result<()> {
+        if scale == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "the scale must be at least 1"));
+        }
+        let magic = match self {
+            Image::Grey(_) => "P5",
+            Image::Rgb(_) => "P6",
+        };
+        write!(out, "{}\n{} {}\n255\n", magic, self.width() * scale, self.height() * scale)?;
+        let mut row = Vec::new();
+        for y in 0..self.height() {
+            row.clear();
+            for x in 0..self.width() {
+                let pixel = match self {
+                    Image::Grey(g) => slice::from_ref(&g[(x, y)]),
+                    Image::Rgb(g) => &g[(x, y)][..],
+                };
+                for _ in 0..scale {
+                    row.extend_from_slice(pixel);
+                }
+            }
+            for _ in 0..scale {
+                out.write_all(&row)?;
+            }
+        }
+        out.flush()
+    }
+
+    /// Writes the image to a file at `path`.
+    pub fn save<P: AsRef<Path>>(&self, path: P, scale: usize) -> io::Result<()> {
+        let path = path.as_ref();
+        File::create(path)
+            .and_then(|f| self.write(BufWriter::new(f), scale))
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+    }
+}
+
+/// A day whose input, or what it turns into, can be saved as an image,
+/// failing for inputs that parse but can't be pictured.
+pub trait Picture: Solution {
+    fn image(input: &Self::Input<'_>) -> Result<Image, Box<dyn Error>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pgm() {
+        let image = Image::points([(0, 0), (2, 1)]);
+        let mut out = Vec::new();
+        image.write(&mut out, 1).unwrap();
+        assert_eq!(out, b"P5\n3 2\n255\n\x00\xff\xff\xff\xff\x00");
+    }
+
+    #[test]
+    fn test_scaled_ppm() {
+        let image = Image::Rgb(Grid::from_rows(vec![vec![[1, 2, 3], [4, 5, 6]]]).unwrap());
+        let mut out = Vec::new();
+        image.write(&mut out, 2).unwrap();
+        let mut expected = b"P6\n4 2\n255\n".to_vec();
+        for _ in 0..2 {
+            expected.extend_from_slice(&[1, 2, 3, 1, 2, 3, 4, 5, 6, 4, 5, 6]);
+        }
+        assert_eq!(out, expected);
+        assert!(image.write(Vec::new(), 0).is_err());
+    }
+
+    #[test]
+    fn test_heat() {
+        let grid = Grid::from_rows(vec![vec![0, 1, 2]]).unwrap();
+        assert_eq!(Image::heat(&grid), Image::Grey(Grid::from_rows(vec![vec![0, 127, 255]]).unwrap()));
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::PathBuf,
+};
+
+use crate::read_input;
+
+/// The directory holding one `<profile>/dXX.txt` tree per person.
+pub const INPUTS_DIR: &str = "inputs";
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+    /// An input compiled into the binary.
+    Embedded(&'static str),
+    /// `<root>/<profile>/dXX.txt`, so that people with different accounts
+    /// can keep their inputs side by side.
+    Profile { root: PathBuf, profile: String },
+}
+
+impl InputSource {
+    /// A command line argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            path => Self::Path(path.into()),
+        }
+    }
+
+    pub fn profile<S: Into<String>>(profile: S) -> Self {
+        Self::Profile {
+            root: INPUTS_DIR.into(),
+            profile: profile.into(),
+        }
+    }
+
+    /// The profile named by the `AOC_PROFILE` environment variable, if set.
+    pub fn from_env() -> Option<Self> {
+        env::var("AOC_PROFILE")
+            .ok()
+            .filter(|p| !p.is_empty())
+            .map(Self::profile)
+    }
+
+    /// What a day binary reads: its first argument, else the `AOC_PROFILE`
+    /// profile, else `input.txt` in the working directory.
+    pub fn from_args() -> Self {
+        Self::from_args_or(Self::Path("input.txt".into()))
+    }
+
+    /// Like [`InputSource::from_args`], but falling back to `default` instead
+    /// of `input.txt`, for days that carry their input in the binary.
+    pub fn from_args_or(default: Self) -> Self {
+        match env::args().nth(1) {
+            Some(arg) => Self::from_arg(&arg),
+            None => Self::from_env().unwrap_or(default),
+        }
+    }
+
+    /// The file this source reads for `day`, if it reads a file at all.
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            Self::Path(path) => Some(path.clone()),
+            Self::Profile { root, profile } => {
+                Some(root.join(profile).join(format!("d{:02}.txt", day)))
+            }
+            Self::Stdin | Self::Embedded(_) => None,
+        }
+    }
+
+    /// Names the input of `day` in reports, the file when there is one.
+    pub fn name(&self, day: u8) -> String {
+        match self.path(day) {
+            Some(path) => path.display().to_string(),
+            None => self.to_string(),
+        }
+    }
+
+    /// Reads the input for `day`. Errors name the file that was tried.
+    pub fn read(&self, day: u8) -> io::Result<String> {
+        match self.path(day) {
+            Some(path) => read_input(&path)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e))),
+            None => match self {
+                Self::Embedded(input) => Ok(input.to_string()),
+                _ => read_input("-"),
+            },
+        }
+    }
+
+    /// Opens the input for `day` to be read a line at a time, instead of
+    /// reading it all at once like [`InputSource::read`].
+    pub fn open(&self, day: u8) -> io::Result<Box<dyn BufRead>> {
+        match self.path(day) {
+            Some(path) => File::open(&path)
+                .map(|f| Box::new(BufReader::new(f)) as Box<dyn BufRead>)
+                .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e))),
+            None => match self {
+                Self::Embedded(input) => Ok(Box::new(input.as_bytes())),
+                _ => Ok(Box::new(io::stdin().lock())),
+            },
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Path(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+            Self::Embedded(_) => write!(f, "embedded input"),
+            Self::Profile { root, profile } => write!(f, "{}", root.join(profile).display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::profile("anthony").path(7),
+            Some(PathBuf::from("inputs/anthony/d07.txt"))
+        );
+        assert_eq!(InputSource::Embedded("1\n").read(1).unwrap(), "1\n");
+        let mut line = String::new();
+        InputSource::Embedded("1\n2\n").open(1).unwrap().read_line(&mut line).unwrap();
+        assert_eq!(line, "1\n");
+        let e = InputSource::Path("missing.txt".into()).read(1).unwrap_err();
+        assert!(e.to_string().starts_with("missing.txt: "));
+    }
+}
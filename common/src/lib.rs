@@ -0,0 +1,119 @@
+use std::{
+    fmt::Display,
+    fs::read_to_string,
+    io::{self, Read},
+    path::Path,
+    str::FromStr,
+};
+
+mod error;
+mod grid;
+mod image;
+mod input;
+mod render;
+mod repl;
+mod solution;
+pub mod trace;
+
+pub use error::{parse_at, ParseError};
+pub use grid::{Grid, Neighbours};
+pub use image::{Image, Picture, Rgb};
+pub use input::{InputSource, INPUTS_DIR};
+pub use render::{Canvas, Cell, Colour, Frame, FrameDump, Terminal, Visualize};
+pub use repl::{count_arg, one_arg, Explore, Session};
+pub use solution::Solution;
+
+/// Reads a whole input file, where the path `-` means stdin.
+pub fn read_input<P: AsRef<Path>>(path: P) -> io::Result<String> {
+    let path = path.as_ref();
+    if path == Path::new("-") {
+        let mut s = String::new();
+        io::stdin().read_to_string(&mut s)?;
+        Ok(s)
+    } else {
+        read_to_string(path)
+    }
+}
+
+/// Parses every non-empty line with `f`.
+///
+/// `f` reports errors against the line it was given, they are moved to the
+/// right line of `input` here.
+pub fn parse_lines_with<'a, T, F>(input: &'a str, mut f: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&'a str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(n, l)| f(l).map_err(|e| e.on_line(n + 1)))
+        .collect()
+}
+
+/// Parses one number per line.
+pub fn parse_numbers<T>(day: u8, input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    parse_lines_with(input, |l| parse_at(day, l, l.trim()))
+}
+
+/// Parses a single line of comma separated values, like `3,4,3,1,2`.
+pub fn parse_comma_list<T>(day: u8, input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input
+        .trim()
+        .split(',')
+        .map(|n| parse_at(day, input, n.trim()))
+        .collect()
+}
+
+/// Parses lines of single digits into rows.
+pub fn parse_digit_grid(day: u8, input: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    parse_lines_with(input, |l| parse_digit_row(day, l))
+}
+
+pub(crate) fn parse_digit_row(day: u8, line: &str) -> Result<Vec<usize>, ParseError> {
+    line.trim_end()
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10).map(|d| d as usize).ok_or_else(|| {
+                ParseError::at(day, line, &line[i..], format!("expected a digit, found `{}`", c))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(parse_numbers::<usize>(1, "199\n200\n\n208\n"), Ok(vec![199, 200, 208]));
+        let e = parse_numbers::<usize>(1, "199\n\n x\n").unwrap_err();
+        assert_eq!((e.day, e.line, e.column), (1, 3, 2));
+    }
+
+    #[test]
+    fn test_comma_list() {
+        assert_eq!(parse_comma_list::<isize>(7, "16,1,2\n"), Ok(vec![16, 1, 2]));
+        let e = parse_comma_list::<isize>(7, "16,1,,2\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 6));
+    }
+
+    #[test]
+    fn test_digit_grid() {
+        assert_eq!(
+            parse_digit_grid(9, "219\n398\n"),
+            Ok(vec![vec![2, 1, 9], vec![3, 9, 8]])
+        );
+        let e = parse_digit_grid(9, "219\n21a\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+    }
+}
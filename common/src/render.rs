@@ -0,0 +1,283 @@
+use std::{
+    error::Error,
+    fmt::Write as _,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use crate::{Grid, Rgb, Solution};
+
+/// The eight standard terminal colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Colour {
+    const ALL: [Colour; 8] = [
+        Colour::Black,
+        Colour::Red,
+        Colour::Green,
+        Colour::Yellow,
+        Colour::Blue,
+        Colour::Magenta,
+        Colour::Cyan,
+        Colour::White,
+    ];
+
+    /// Cycles through the colours that show up on a dark background, for
+    /// telling regions apart.
+    pub fn nth(n: usize) -> Self {
+        Self::ALL[1 + n % 7]
+    }
+
+    /// The colour as terminals usually show it.
+    pub fn rgb(self) -> Rgb {
+        match self {
+            Colour::Black => [0, 0, 0],
+            Colour::Red => [205, 0, 0],
+            Colour::Green => [0, 205, 0],
+            Colour::Yellow => [205, 205, 0],
+            Colour::Blue => [0, 0, 238],
+            Colour::Magenta => [205, 0, 205],
+            Colour::Cyan => [0, 205, 205],
+            Colour::White => [229, 229, 229],
+        }
+    }
+
+    fn ansi(self) -> u8 {
+        30 + self as u8
+    }
+}
+
+/// One character of a frame and the colour it has on a terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub ch: char,
+    pub colour: Option<Colour>,
+}
+
+impl Cell {
+    pub fn new(ch: char, colour: Colour) -> Self {
+        Self {
+            ch,
+            colour: Some(colour),
+        }
+    }
+}
+
+impl From<char> for Cell {
+    fn from(ch: char) -> Self {
+        Self { ch, colour: None }
+    }
+}
+
+/// A snapshot of a grid, with every value mapped to a [`Cell`], and a
+/// caption drawn above it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    caption: String,
+    cells: Grid<Cell>,
+}
+
+impl Frame {
+    pub fn new<T, F, C>(grid: &Grid<T>, mut f: F) -> Self
+    where
+        F: FnMut(&T) -> C,
+        C: Into<Cell>,
+    {
+        Self {
+            caption: String::new(),
+            cells: grid.map(|v| f(v).into()),
+        }
+    }
+
+    pub fn with_caption<S: Into<String>>(mut self, caption: S) -> Self {
+        self.caption = caption.into();
+        self
+    }
+
+    pub fn caption(&self) -> &str {
+        &self.caption
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// The rows without colours or caption, joined by newlines.
+    pub fn grid_text(&self) -> String {
+        self.cells
+            .rows()
+            .map(|row| row.iter().map(|c| c.ch).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The caption, if any, and the rows, ending in a newline.
+    pub fn plain(&self) -> String {
+        let mut s = String::new();
+        if !self.caption.is_empty() {
+            s.push_str(&self.caption);
+            s.push('\n');
+        }
+        s.push_str(&self.grid_text());
+        s.push('\n');
+        s
+    }
+
+    /// Like [`Frame::plain`], with ANSI escapes for the colours.
+    pub fn ansi(&self) -> String {
+        let mut s = String::new();
+        if !self.caption.is_empty() {
+            s.push_str(&self.caption);
+            s.push('\n');
+        }
+        for row in self.cells.rows() {
+            let mut current = None;
+            for cell in row {
+                if cell.colour != current {
+                    match cell.colour {
+                        Some(c) => write!(s, "\x1b[{}m", c.ansi()).expect("writing to a string"),
+                        None => s.push_str("\x1b[0m"),
+                    }
+                    current = cell.colour;
+                }
+                s.push(cell.ch);
+            }
+            if current.is_some() {
+                s.push_str("\x1b[0m");
+            }
+            s.push('\n');
+        }
+        s
+    }
+
+    /// How many lines the frame takes up.
+    pub fn lines(&self) -> usize {
+        self.cells.height() + usize::from(!self.caption.is_empty())
+    }
+}
+
+/// Somewhere frames are drawn, one after the other.
+pub trait Canvas {
+    fn draw(&mut self, frame: &Frame) -> io::Result<()>;
+}
+
+/// Draws each frame over the one before it, waiting `delay` in between.
+#[derive(Debug)]
+pub struct Terminal<W: Write> {
+    out: W,
+    delay: Duration,
+    colour: bool,
+    last: Option<usize>,
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W, delay: Duration, colour: bool) -> Self {
+        Self {
+            out,
+            delay,
+            colour,
+            last: None,
+        }
+    }
+}
+
+impl<W: Write> Canvas for Terminal<W> {
+    fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        if let Some(lines) = self.last {
+            thread::sleep(self.delay);
+            // back to the start of the last frame, then clear everything below
+            write!(self.out, "\x1b[{}F\x1b[J", lines)?;
+        }
+        match self.colour {
+            true => self.out.write_all(frame.ansi().as_bytes())?,
+            false => self.out.write_all(frame.plain().as_bytes())?,
+        }
+        self.last = Some(frame.lines());
+        self.out.flush()
+    }
+}
+
+/// Writes each frame to its own plain text file, `frame_0000.txt` and up.
+#[derive(Debug)]
+pub struct FrameDump {
+    dir: PathBuf,
+    next: usize,
+}
+
+impl FrameDump {
+    /// Creates `dir` if it doesn't exist yet.
+    pub fn new<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir: dir.as_ref().to_path_buf(),
+            next: 0,
+        })
+    }
+}
+
+impl Canvas for FrameDump {
+    fn draw(&mut self, frame: &Frame) -> io::Result<()> {
+        let path = self.dir.join(format!("frame_{:04}.txt", self.next));
+        fs::write(&path, frame.plain())
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+        self.next += 1;
+        Ok(())
+    }
+}
+
+/// A day whose state can be drawn step by step. Like the parts, drawing
+/// fails for inputs that parse but can't be drawn.
+pub trait Visualize: Solution {
+    fn frames(input: &Self::Input<'_>) -> Result<Vec<Frame>, Box<dyn Error>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_text() {
+        let grid = Grid::from_rows(vec![vec![1, 0], vec![0, 1]]).unwrap();
+        let frame = Frame::new(&grid, |&v| match v {
+            1 => Cell::new('#', Colour::Red),
+            _ => Cell::from('.'),
+        })
+        .with_caption("step 1");
+        assert_eq!(frame.grid_text(), "#.\n.#");
+        assert_eq!(frame.plain(), "step 1\n#.\n.#\n");
+        assert_eq!(
+            frame.ansi(),
+            "step 1\n\x1b[31m#\x1b[0m.\n.\x1b[31m#\x1b[0m\n"
+        );
+        assert_eq!(frame.lines(), 3);
+    }
+
+    #[test]
+    fn test_dump() {
+        let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let mut dump = FrameDump::new(&dir).unwrap();
+        let grid = Grid::filled(2, 1, 'x');
+        for caption in ["a", "b"] {
+            dump.draw(&Frame::new(&grid, |&c| c).with_caption(caption))
+                .unwrap();
+        }
+        assert_eq!(
+            fs::read_to_string(dir.join("frame_0001.txt")).unwrap(),
+            "b\nxx\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
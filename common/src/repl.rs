@@ -0,0 +1,51 @@
+use crate::Solution;
+
+/// The state of a day that `aoc repl` changes one command at a time.
+pub trait Session {
+    /// The day's own commands, one `name <args>: what it does` per line.
+    fn help(&self) -> &'static str;
+
+    /// The state, printed after every command.
+    fn state(&self) -> String;
+
+    /// Runs `command` with `args`, or explains why it could not be run. What
+    /// is returned is printed above the state.
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<Option<String>, String>;
+}
+
+/// A day that can be explored with `aoc repl`.
+pub trait Explore: Solution {
+    fn session(input: Self::Input<'_>) -> Box<dyn Session + '_>;
+}
+
+/// The number in `args`, or 1 without arguments, for commands like `step 10`.
+pub fn count_arg(args: &[&str]) -> Result<usize, String> {
+    match args {
+        [] => Ok(1),
+        [n] => n
+            .parse()
+            .map_err(|_| format!("expected a number of times, found `{}`", n)),
+        _ => Err(format!("expected at most one argument, found {}", args.len())),
+    }
+}
+
+/// The one argument in `args`, like the hex string of `decode <hex>`.
+pub fn one_arg<'a>(args: &[&'a str], what: &str) -> Result<&'a str, String> {
+    match args {
+        [arg] => Ok(arg),
+        _ => Err(format!("expected {}", what)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_arg() {
+        assert_eq!(count_arg(&[]), Ok(1));
+        assert_eq!(count_arg(&["18"]), Ok(18));
+        assert!(count_arg(&["x"]).is_err());
+        assert!(count_arg(&["1", "2"]).is_err());
+    }
+}
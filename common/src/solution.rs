@@ -0,0 +1,18 @@
+use std::{error::Error, fmt::Display};
+
+use crate::ParseError;
+
+/// A day of the calendar, split into parsing and the two parts. A part
+/// fails for inputs that parse but have no answer.
+pub trait Solution {
+    const DAY: u8;
+
+    /// The parsed puzzle input, which may borrow from the input text.
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>>;
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>>;
+}
@@ -0,0 +1,207 @@
+//! Spans and counters for the hot loops of the solvers.
+//!
+//! [`trace_span!`](crate::trace_span) and [`trace_count!`](crate::trace_count)
+//! compile to nothing unless the `trace` feature is on, so the solvers can
+//! use them freely.
+
+/// Times the rest of the enclosing block, `let _span = trace_span!("d15 sweeps");`.
+#[cfg(feature = "trace")]
+#[macro_export]
+macro_rules! trace_span {
+    ($name:literal) => {
+        $crate::trace::tracing::trace_span!($name).entered()
+    };
+}
+
+/// Adds `n` to the counter `name`, `trace_count!("d18 explodes", 1);`.
+#[cfg(feature = "trace")]
+#[macro_export]
+macro_rules! trace_count {
+    ($name:literal, $n:expr) => {
+        $crate::trace::tracing::trace!(counter = $name, value = ($n) as u64)
+    };
+}
+
+/// Times the rest of the enclosing block, `let _span = trace_span!("d15 sweeps");`.
+#[cfg(not(feature = "trace"))]
+#[macro_export]
+macro_rules! trace_span {
+    ($name:literal) => {
+        $crate::trace::Untraced
+    };
+}
+
+/// Adds `n` to the counter `name`, `trace_count!("d18 explodes", 1);`.
+#[cfg(not(feature = "trace"))]
+#[macro_export]
+macro_rules! trace_count {
+    ($name:literal, $n:expr) => {{
+        let _ = &$n;
+    }};
+}
+
+/// What [`trace_span!`](crate::trace_span) gives without the `trace` feature.
+#[cfg(not(feature = "trace"))]
+#[doc(hidden)]
+pub struct Untraced;
+
+#[cfg(feature = "trace")]
+pub use recorder::{record, CounterStats, SpanStats, Stats};
+#[cfg(feature = "trace")]
+#[doc(hidden)]
+pub use tracing;
+
+#[cfg(feature = "trace")]
+mod recorder {
+    use std::{
+        collections::BTreeMap,
+        fmt,
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Subscriber,
+    };
+    use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, Layer, Registry};
+
+    /// How often a span was entered and how long it ran in total.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct SpanStats {
+        pub name: String,
+        pub calls: u64,
+        pub busy: Duration,
+    }
+
+    /// How often a counter was added to, the sum and the largest addition.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct CounterStats {
+        pub name: String,
+        pub events: u64,
+        pub total: u64,
+        pub max: u64,
+    }
+
+    /// Everything recorded by [`record`], sorted by name.
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct Stats {
+        pub spans: Vec<SpanStats>,
+        pub counters: Vec<CounterStats>,
+    }
+
+    #[derive(Debug, Default)]
+    struct Collected {
+        spans: BTreeMap<&'static str, (u64, Duration)>,
+        counters: BTreeMap<String, (u64, u64, u64)>,
+    }
+
+    struct Start(Instant);
+
+    struct Summary(Arc<Mutex<Collected>>);
+
+    #[derive(Default)]
+    struct Counter {
+        name: Option<String>,
+        value: u64,
+    }
+
+    impl Visit for Counter {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "counter" {
+                self.name = Some(value.to_string());
+            }
+        }
+
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            if field.name() == "value" {
+                self.value = value;
+            }
+        }
+
+        fn record_debug(&mut self, _field: &Field, _value: &dyn fmt::Debug) {}
+    }
+
+    impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Summary {
+        fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+            if let Some(span) = ctx.span(id) {
+                span.extensions_mut().replace(Start(Instant::now()));
+            }
+        }
+
+        fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+            let Some(span) = ctx.span(id) else { return };
+            let Some(Start(start)) = span.extensions_mut().remove::<Start>() else {
+                return;
+            };
+            let mut collected = self.0.lock().expect("a solver panicked while tracing");
+            let entry = collected.spans.entry(span.name()).or_default();
+            entry.0 += 1;
+            entry.1 += start.elapsed();
+        }
+
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            let mut counter = Counter::default();
+            event.record(&mut counter);
+            if let Some(name) = counter.name {
+                let mut collected = self.0.lock().expect("a solver panicked while tracing");
+                let entry = collected.counters.entry(name).or_default();
+                entry.0 += 1;
+                entry.1 += counter.value;
+                entry.2 = entry.2.max(counter.value);
+            }
+        }
+    }
+
+    /// Runs `f`, recording the spans and counters it goes through on this
+    /// thread.
+    pub fn record<T>(f: impl FnOnce() -> T) -> (T, Stats) {
+        let collected = Arc::new(Mutex::new(Collected::default()));
+        let subscriber = Registry::default().with(Summary(Arc::clone(&collected)));
+        let result = tracing::subscriber::with_default(subscriber, f);
+        let collected = collected.lock().expect("a solver panicked while tracing");
+        let stats = Stats {
+            spans: collected
+                .spans
+                .iter()
+                .map(|(name, &(calls, busy))| SpanStats {
+                    name: name.to_string(),
+                    calls,
+                    busy,
+                })
+                .collect(),
+            counters: collected
+                .counters
+                .iter()
+                .map(|(name, &(events, total, max))| CounterStats {
+                    name: name.clone(),
+                    events,
+                    total,
+                    max,
+                })
+                .collect(),
+        };
+        (result, stats)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_record() {
+            let (answer, stats) = record(|| {
+                for n in 1..=3 {
+                    let _span = crate::trace_span!("loop");
+                    crate::trace_count!("items", n);
+                }
+                42
+            });
+            assert_eq!(answer, 42);
+            assert_eq!(stats.spans.len(), 1);
+            assert_eq!((stats.spans[0].name.as_str(), stats.spans[0].calls), ("loop", 3));
+            let items = &stats.counters[0];
+            assert_eq!((items.events, items.total, items.max), (3, 6, 3));
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Counting increases in a report that is still being written.
+
+use std::{
+    error::Error,
+    fs::{self, File, Metadata},
+    io::{self, BufRead, BufReader, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use aoc_common::ParseError;
+
+use crate::stream::{Readings, WindowCounter};
+
+/// What a [`Follower::poll`] found.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Update {
+    /// How many new lines were read.
+    pub lines: usize,
+    /// The file got shorter, so it was read again from the start with the
+    /// counts starting over.
+    pub truncated: bool,
+    /// Another file took the place of the one being read. The rest of the
+    /// old file was read first, and the counts go on with the new one.
+    pub rotated: bool,
+    /// The new lines that weren't measurements, which were skipped.
+    pub invalid: Vec<ParseError>,
+}
+
+impl Update {
+    /// Whether nothing happened since the last poll.
+    pub fn is_empty(&self) -> bool {
+        self.lines == 0 && !self.truncated && !self.rotated
+    }
+}
+
+/// The device and inode of a file, to tell when a path points to another
+/// file. Without them only truncation is noticed.
+#[cfg(unix)]
+fn identity(meta: &Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((meta.dev(), meta.ino()))
+}
+
+#[cfg(not(unix))]
+fn identity(_meta: &Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+/// Keeps a report open and counts the increases of every window size as
+/// lines are added to it, without reading it again.
+#[derive(Debug)]
+pub struct Follower {
+    path: PathBuf,
+    reader: BufReader<File>,
+    identity: Option<(u64, u64)>,
+    offset: u64,
+    pending: Vec<u8>,
+    line: usize,
+    readings: Readings,
+    sizes: Vec<usize>,
+    counters: Vec<WindowCounter>,
+}
+
+impl Follower {
+    /// Opens `path` without reading it yet, the first [`Follower::poll`]
+    /// reads what is already there.
+    pub fn open<P: AsRef<Path>>(path: P, readings: &Readings, sizes: &[usize]) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = File::open(&path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+        Ok(Self {
+            identity: identity(&file.metadata()?),
+            reader: BufReader::new(file),
+            path,
+            offset: 0,
+            pending: Vec::new(),
+            line: 0,
+            readings: *readings,
+            sizes: sizes.to_vec(),
+            counters: sizes.iter().map(|&k| WindowCounter::new(k)).collect(),
+        })
+    }
+
+    /// The increases of each window size so far.
+    pub fn increases(&self) -> Vec<usize> {
+        self.counters.iter().map(WindowCounter::increases).collect()
+    }
+
+    /// Reads the lines added since the last poll. A last line without a
+    /// newline waits until it is finished.
+    pub fn poll(&mut self) -> Result<Update, Box<dyn Error>> {
+        let mut update = Update::default();
+        let meta = match fs::metadata(&self.path) {
+            Ok(meta) => Some(meta),
+            // in the middle of a rotation, the old file may still grow
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+        if let Some(meta) = meta {
+            if identity(&meta) != self.identity {
+                update.lines += self.read_new(true, &mut update.invalid)?;
+                let file = File::open(&self.path)?;
+                self.identity = identity(&file.metadata()?);
+                self.reader = BufReader::new(file);
+                self.offset = 0;
+                self.line = 0;
+                update.rotated = true;
+            } else if meta.len() < self.offset {
+                self.reader.seek(SeekFrom::Start(0))?;
+                self.offset = 0;
+                self.pending.clear();
+                self.line = 0;
+                self.counters = self.sizes.iter().map(|&k| WindowCounter::new(k)).collect();
+                update.truncated = true;
+            }
+        }
+        update.lines += self.read_new(false, &mut update.invalid)?;
+        Ok(update)
+    }
+
+    /// Reads every finished line, and with `to_end` an unfinished last one,
+    /// adding the lines that aren't measurements to `invalid`.
+    fn read_new(
+        &mut self,
+        to_end: bool,
+        invalid: &mut Vec<ParseError>,
+    ) -> Result<usize, Box<dyn Error>> {
+        let mut lines = 0;
+        loop {
+            let read = self.reader.read_until(b'\n', &mut self.pending)?;
+            self.offset += read as u64;
+            let finished =
+                self.pending.ends_with(b"\n") || (to_end && read == 0 && !self.pending.is_empty());
+            match (finished, read) {
+                (false, 0) => return Ok(lines),
+                (false, _) => continue,
+                (true, _) => {}
+            }
+            self.line += 1;
+            lines += 1;
+            match self.readings.parse_bytes(&self.pending, self.line) {
+                Ok(Some(depth)) => self.counters.iter_mut().for_each(|c| c.push(depth)),
+                Ok(None) => {}
+                Err(e) => invalid.push(e),
+            }
+            self.pending.clear();
+        }
+    }
+}
+
+/// Follows `path`, calling `on_update` with the counts of every window size
+/// whenever lines are added, checking every `interval`. Runs until reading
+/// fails.
+pub fn follow<P, F>(
+    path: P,
+    readings: &Readings,
+    sizes: &[usize],
+    interval: Duration,
+    mut on_update: F,
+) -> Result<(), Box<dyn Error>>
+where
+    P: AsRef<Path>,
+    F: FnMut(Update, &[usize]),
+{
+    let mut follower = Follower::open(path, readings, sizes)?;
+    loop {
+        let update = follower.poll()?;
+        if !update.is_empty() {
+            on_update(update, &follower.increases());
+        }
+        thread::sleep(interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_follow() {
+        let dir = std::env::temp_dir().join(format!("aoc-d01-follow-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("depths.txt");
+        let append = |text: &str| {
+            let mut f = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .unwrap();
+            f.write_all(text.as_bytes()).unwrap();
+        };
+        append("199\n200\n");
+        let mut follower = Follower::open(&path, &Readings::default(), &[1, 3]).unwrap();
+        assert_eq!(follower.poll().unwrap().lines, 2);
+        assert_eq!(follower.increases(), [1, 0]);
+
+        append("208\n21");
+        assert_eq!(follower.poll().unwrap().lines, 1);
+        append("0\n200\nx\n");
+        let update = follower.poll().unwrap();
+        assert_eq!(update.lines, 3);
+        assert_eq!(
+            update.invalid.iter().map(|e| e.line).collect::<Vec<_>>(),
+            [6]
+        );
+        assert_eq!(follower.increases(), [3, 1]);
+        assert_eq!(follower.poll().unwrap(), Update::default());
+
+        fs::write(&path, "5\n").unwrap();
+        let update = follower.poll().unwrap();
+        assert!(update.truncated && !update.rotated);
+        assert_eq!(follower.increases(), [0, 0]);
+
+        append("4");
+        fs::rename(&path, dir.join("depths.txt.1")).unwrap();
+        append("6\n");
+        let update = follower.poll().unwrap();
+        assert_eq!((update.lines, update.rotated), (2, true));
+        assert_eq!(follower.increases(), [1, 0]);
+        append("x\n");
+        let update = follower.poll().unwrap();
+        assert_eq!(
+            update.invalid.iter().map(|e| e.line).collect::<Vec<_>>(),
+            [2]
+        );
+
+        let e = Follower::open(dir.join("missing.txt"), &Readings::default(), &[1]).unwrap_err();
+        assert!(e.to_string().contains("missing.txt"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,14 @@
+//! Random sonar sweeps.
+
+use rand::{Rng, RngExt};
+
+/// `size` depth measurements, drifting up and down like a real sea floor.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut depth: u32 = rng.random_range(100..200);
+    (0..size)
+        .map(|_| {
+            depth = depth.saturating_add_signed(rng.random_range(-10..=30)).max(1);
+            format!("{}\n", depth)
+        })
+        .collect()
+}
@@ -0,0 +1,69 @@
+//! Day 1: Sonar Sweep, counting how often the sea floor gets deeper.
+#![warn(missing_docs)]
+
+use std::{error::Error, path::Path};
+
+use aoc_common::{read_input, ParseError, Solution};
+use stream::{Depth, Readings};
+
+pub mod follow;
+pub mod generate;
+pub mod report;
+pub mod stream;
+
+/// Reads and parses the sonar report at `path` with the puzzle's readings.
+pub fn read_lines_as_numbers<P: AsRef<Path>>(path: P) -> Result<Vec<Depth>, Box<dyn Error>> {
+    Ok(parse_input(&read_input(path)?, &Readings::default())?)
+}
+
+/// One measurement per line, skipping blank lines and `#` comments.
+///
+/// Unlike [`stream::read_depths`], a line that isn't a measurement is an
+/// error rather than skipped: `aoc` checks the answers against registered
+/// inputs, which a skipped line would quietly change.
+pub fn parse_input(input: &str, readings: &Readings) -> Result<Vec<Depth>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(n, l)| readings.parse(l, n + 1).transpose())
+        .collect()
+}
+
+/// How many sums of `size` measurements in a row are deeper than the sum
+/// before, comparing only the measurements the two windows don't share.
+pub fn increases(input: &[Depth], size: usize) -> usize {
+    input.windows(size + 1).filter(|w| w[0] < w[size]).count()
+}
+
+/// How many measurements are deeper than the one before.
+pub fn part1(input: &[Depth]) -> usize {
+    increases(input, 1)
+}
+
+/// How many sums of three measurements in a row are deeper than the sum before.
+pub fn part2(input: &[Depth]) -> usize {
+    increases(input, 3)
+}
+
+/// The [`Solution`] of day 1.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<Depth>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input, &Readings::default())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input))
+    }
+}
@@ -1,24 +1,99 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader, Result},
-    path::Path,
+use std::{env, error::Error, time::Duration};
+
+use aoc_common::{InputSource, ParseError, Solution};
+use d01::{
+    follow::follow,
+    report::stats_from,
+    stream::{increases_from, Readings},
+    Day01,
 };
 
-fn read_lines_as_numbers<P: AsRef<Path>>(path: P) -> Result<Vec<usize>> {
-    let f = BufReader::new(File::open(path)?);
-    f.lines()
-        .map(|l| Ok(l?.parse::<usize>().unwrap()))
-        .collect()
-}
+/// How often `--follow` looks for new lines.
+const FOLLOW_INTERVAL: Duration = Duration::from_millis(250);
 
-fn increases(input: &[usize]) -> usize {
-    input.windows(2).filter(|i| i[0] < i[1]).count()
+/// `d01 [<input>] [--window <size>]... [--signed] [--decimal] [--report | --follow]`,
+/// streaming the input so that it never has to fit in memory. Without
+/// `--window` it prints both parts, `--report` gives the statistics of each
+/// window size instead and `--follow` keeps counting as lines are added to
+/// the input file. `--signed` and `--decimal` accept negative and fractional
+/// measurements, lines that aren't measurements are reported and skipped.
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1);
+    let mut input = None;
+    let mut sizes = Vec::new();
+    let mut readings = Readings::default();
+    let (mut report, mut following) = (false, false);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--window" => {
+                let size = args.next().ok_or("`--window` needs a size")?;
+                match size.parse() {
+                    Ok(0) | Err(_) => return Err(format!("invalid window size `{}`", size).into()),
+                    Ok(size) => sizes.push(size),
+                }
+            }
+            "--signed" => readings.signed = true,
+            "--decimal" => readings.decimal = true,
+            "--report" => report = true,
+            "--follow" => following = true,
+            _ if input.is_none() => input = Some(InputSource::from_arg(&arg)),
+            _ => return Err(format!("unexpected argument `{}`", arg).into()),
+        }
+    }
+    if report && following {
+        return Err("`--report` and `--follow` can't be used together".into());
+    }
+    let source = input
+        .or_else(InputSource::from_env)
+        .unwrap_or_else(|| InputSource::Path("input.txt".into()));
+    let labels = match sizes.is_empty() {
+        true => {
+            sizes = vec![1, 3];
+            vec!["Part 1".to_string(), "Part 2".to_string()]
+        }
+        false => sizes.iter().map(|k| format!("Window {}", k)).collect(),
+    };
+    if following {
+        let path = source.path(Day01::DAY).ok_or("`--follow` needs a file")?;
+        return follow(
+            &path,
+            &readings,
+            &sizes,
+            FOLLOW_INTERVAL,
+            |update, counts| {
+                if update.truncated {
+                    eprintln!("{} was truncated, counting from the start", path.display());
+                }
+                update.invalid.iter().for_each(skipped);
+                if update.rotated {
+                    eprintln!(
+                        "{} was replaced, counting on with the new file",
+                        path.display()
+                    );
+                }
+                let counts = labels
+                    .iter()
+                    .zip(counts)
+                    .map(|(l, c)| format!("{}: {}", l, c));
+                println!("{}", counts.collect::<Vec<_>>().join(", "));
+            },
+        );
+    }
+    let reader = source.open(Day01::DAY)?;
+    if report {
+        for stats in stats_from(reader, &readings, &sizes, |e| skipped(&e))? {
+            print!("{}", stats);
+        }
+        return Ok(());
+    }
+    let counts = increases_from(reader, &readings, &sizes, |e| skipped(&e))?;
+    for (label, count) in labels.iter().zip(counts) {
+        println!("{}: {}", label, count);
+    }
+    Ok(())
 }
 
-fn main() -> Result<()> {
-    let input = read_lines_as_numbers("input.txt")?;
-    println!("Part1: {}", increases(&input));
-    let part2: Vec<usize> = input.windows(3).map(|t| t.iter().sum()).collect();
-    println!("Part2: {}", increases(&part2));
-    Ok(())
+/// Reports a line that isn't a measurement without stopping.
+fn skipped(e: &ParseError) {
+    eprintln!("skipping {}", e);
 }
@@ -0,0 +1,444 @@
+//! More about the windows than how often they get deeper.
+
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{self, Display},
+    io::BufRead,
+};
+
+use aoc_common::ParseError;
+
+use crate::stream::{read_depths, Depth, Readings, Window};
+
+/// Windows getting deeper, or shallower, several times in a row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    /// Whether each window is deeper than the one before.
+    pub deeper: bool,
+    /// How many times in a row.
+    pub length: usize,
+    /// The line the window before the first change ends on.
+    pub start_line: usize,
+    /// The line the last window of the run ends on.
+    pub end_line: usize,
+}
+
+/// The largest difference between a window and the one before.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Jump {
+    /// How much deeper the window is, negative when it is shallower.
+    pub delta: f64,
+    /// The line the window ends on.
+    pub line: usize,
+}
+
+/// How many ranges [`Deltas`] counts in at most, which bounds its memory.
+const BINS: i64 = 1024;
+
+/// The width of the narrowest ranges. A power of two, so that whole
+/// differences always start a range of at most 1.
+const FINEST: f64 = 1.0 / 1024.0;
+
+/// The differences between windows counted in ranges of the same width,
+/// which doubles whenever the differences spread over more than [`BINS`]
+/// of them. The ranges start at whole multiples of the width from the first
+/// difference, so every range of the doubled width holds two of the old
+/// ones.
+#[derive(Debug, Clone, Default)]
+struct Deltas {
+    origin: f64,
+    width: f64,
+    bins: BTreeMap<i64, usize>,
+    min: f64,
+    max: f64,
+    whole: bool,
+}
+
+impl Deltas {
+    fn push(&mut self, delta: f64) {
+        // decimal readings far apart can differ by more than an f64 holds,
+        // and the spread of the differences has to fit too
+        let delta = delta.clamp(f64::MIN / 2.0, f64::MAX / 2.0);
+        if self.bins.is_empty() {
+            *self = Self {
+                origin: delta,
+                width: FINEST,
+                bins: BTreeMap::new(),
+                min: delta,
+                max: delta,
+                whole: true,
+            };
+        }
+        self.min = self.min.min(delta);
+        self.max = self.max.max(delta);
+        self.whole &= delta.fract() == 0.0;
+        loop {
+            // in f64, as far differences are more bins apart than an i64 holds
+            // until the width has grown to fit them
+            let bin = ((delta - self.origin) / self.width).floor();
+            let low = self
+                .bins
+                .first_key_value()
+                .map_or(bin, |(&b, _)| bin.min(b as f64));
+            let high = self
+                .bins
+                .last_key_value()
+                .map_or(bin, |(&b, _)| bin.max(b as f64));
+            if high - low < BINS as f64 {
+                *self.bins.entry(bin as i64).or_default() += 1;
+                return;
+            }
+            self.width *= 2.0;
+            let mut bins = BTreeMap::new();
+            for (bin, n) in std::mem::take(&mut self.bins) {
+                *bins.entry(bin.div_euclid(2)).or_default() += n;
+            }
+            self.bins = bins;
+        }
+    }
+
+    /// Where each range starts and how many differences fell into it.
+    fn iter(&self) -> impl Iterator<Item = (f64, usize)> + '_ {
+        self.bins
+            .iter()
+            .map(|(&bin, &n)| (self.origin + bin as f64 * self.width, n))
+    }
+}
+
+/// Statistics of the windows of `size` measurements, gathered one
+/// measurement at a time.
+#[derive(Debug, Clone)]
+pub struct WindowStats {
+    size: usize,
+    window: Window,
+    last_line: usize,
+    increases: usize,
+    decreases: usize,
+    plateaus: usize,
+    current: Option<Run>,
+    longest: Option<Run>,
+    largest_jump: Option<Jump>,
+    deltas: Deltas,
+}
+
+impl WindowStats {
+    /// # Panics
+    ///
+    /// When `size` is 0.
+    pub fn new(size: usize) -> Self {
+        Self {
+            size,
+            window: Window::new(size),
+            last_line: 0,
+            increases: 0,
+            decreases: 0,
+            plateaus: 0,
+            current: None,
+            longest: None,
+            largest_jump: None,
+            deltas: Deltas::default(),
+        }
+    }
+
+    /// Adds the measurement on `line`.
+    pub fn push(&mut self, line: usize, depth: Depth) {
+        let previous_line = std::mem::replace(&mut self.last_line, line);
+        let Some(delta) = self.window.slide(depth) else {
+            return;
+        };
+        self.deltas.push(delta);
+        if self
+            .largest_jump
+            .is_none_or(|j| delta.abs() > j.delta.abs())
+        {
+            self.largest_jump = Some(Jump { delta, line });
+        }
+        if delta == 0.0 {
+            self.plateaus += 1;
+            self.current = None;
+            return;
+        }
+        let deeper = delta > 0.0;
+        match deeper {
+            true => self.increases += 1,
+            false => self.decreases += 1,
+        }
+        let run = match self.current {
+            Some(run) if run.deeper == deeper => Run {
+                length: run.length + 1,
+                end_line: line,
+                ..run
+            },
+            _ => Run {
+                deeper,
+                length: 1,
+                start_line: previous_line,
+                end_line: line,
+            },
+        };
+        if self.longest.is_none_or(|l| run.length > l.length) {
+            self.longest = Some(run);
+        }
+        self.current = Some(run);
+    }
+
+    /// How many measurements each window sums.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// How many windows were deeper than the one before.
+    pub fn increases(&self) -> usize {
+        self.increases
+    }
+
+    /// How many windows were shallower than the one before.
+    pub fn decreases(&self) -> usize {
+        self.decreases
+    }
+
+    /// How many windows were as deep as the one before.
+    pub fn plateaus(&self) -> usize {
+        self.plateaus
+    }
+
+    /// The longest run, the first of them on a tie.
+    pub fn longest_run(&self) -> Option<Run> {
+        self.longest
+    }
+
+    /// The largest jump either way, the first of them on a tie.
+    pub fn largest_jump(&self) -> Option<Jump> {
+        self.largest_jump
+    }
+
+    /// How often each difference between a window and the one before came
+    /// up, from the smallest difference to the largest. To keep to the same
+    /// memory on any report, differences within a 1024th of the spread of
+    /// them all may be counted together, under where their range starts.
+    /// Whole differences less than 1024 apart are always exact.
+    pub fn deltas(&self) -> impl Iterator<Item = (f64, usize)> + '_ {
+        self.deltas.iter()
+    }
+
+    /// The differences split into at most `buckets` ranges of the same
+    /// width, from the smallest difference to the largest, with how many
+    /// fell into each. Whole differences give ranges that include their
+    /// end, the others ranges up to their end.
+    pub fn histogram(&self, buckets: usize) -> Vec<(f64, f64, usize)> {
+        if self.deltas.bins.is_empty() {
+            return Vec::new();
+        }
+        let (min, max) = (self.deltas.min, self.deltas.max);
+        let buckets = buckets.max(1) as f64;
+        let whole = self.deltas.whole;
+        let (width, count) = match whole {
+            true => {
+                let width = ((max - min + 1.0) / buckets).ceil();
+                (width, ((max - min + 1.0) / width).ceil())
+            }
+            false if max > min => ((max - min) / buckets, buckets),
+            false => (1.0, 1.0),
+        };
+        let mut histogram = (0..count as usize)
+            .map(|i| {
+                let low = min + i as f64 * width;
+                let high = if whole {
+                    low + width - 1.0
+                } else {
+                    low + width
+                };
+                (low, high, 0)
+            })
+            .collect::<Vec<_>>();
+        let last = histogram.len() - 1;
+        for (delta, n) in self.deltas() {
+            histogram[(((delta - min) / width) as usize).min(last)].2 += n;
+        }
+        histogram
+    }
+}
+
+impl Display for WindowStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Window {}: {} increases, {} decreases, {} plateaus",
+            self.size, self.increases, self.decreases, self.plateaus
+        )?;
+        if let Some(run) = self.longest {
+            writeln!(
+                f,
+                "  longest run: {} {} in a row, from line {} to line {}",
+                run.length,
+                if run.deeper { "increases" } else { "decreases" },
+                run.start_line,
+                run.end_line
+            )?;
+        }
+        if let Some(jump) = self.largest_jump {
+            writeln!(f, "  largest jump: {:+} at line {}", jump.delta, jump.line)?;
+        }
+        let histogram = self.histogram(10);
+        let most = histogram.iter().map(|b| b.2).max().unwrap_or(0).max(1);
+        let whole = self.deltas.whole;
+        for (low, high, count) in histogram {
+            let bar = "#".repeat((count * 40).div_ceil(most));
+            let range = match whole {
+                true => format!("{:>7} ..= {:<7}", low, high),
+                false => format!("{:>7.2} ..  {:<7.2}", low, high),
+            };
+            let row = format!("  {} {:>8} {}", range, count, bar);
+            writeln!(f, "{}", row.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads one measurement per line from `reader`, gathering the statistics
+/// of every window size in `sizes` in a single pass. Invalid lines go to
+/// `on_invalid`.
+pub fn stats_from<R, E>(
+    reader: R,
+    readings: &Readings,
+    sizes: &[usize],
+    on_invalid: E,
+) -> Result<Vec<WindowStats>, Box<dyn Error>>
+where
+    R: BufRead,
+    E: FnMut(ParseError),
+{
+    let mut stats = sizes
+        .iter()
+        .map(|&k| WindowStats::new(k))
+        .collect::<Vec<_>>();
+    read_depths(
+        reader,
+        readings,
+        |line, depth| stats.iter_mut().for_each(|s| s.push(line, depth)),
+        on_invalid,
+    )?;
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    #[test]
+    fn test_example() {
+        let stats = stats_from(EXAMPLE.as_bytes(), &Readings::default(), &[1, 3], |e| {
+            panic!("{}", e)
+        })
+        .unwrap();
+        let single = &stats[0];
+        assert_eq!(
+            (single.increases(), single.decreases(), single.plateaus()),
+            (7, 2, 0)
+        );
+        let run = Run {
+            deeper: true,
+            length: 3,
+            start_line: 1,
+            end_line: 4,
+        };
+        assert_eq!(single.longest_run(), Some(run));
+        assert_eq!(
+            single.largest_jump(),
+            Some(Jump {
+                delta: 33.0,
+                line: 7
+            })
+        );
+        assert_eq!(single.histogram(10)[0], (-10.0, -6.0, 2));
+        assert_eq!(single.histogram(10).iter().map(|b| b.2).sum::<usize>(), 9);
+        let triple = &stats[1];
+        assert_eq!(
+            (triple.increases(), triple.decreases(), triple.plateaus()),
+            (5, 1, 1)
+        );
+    }
+
+    #[test]
+    fn test_blank_lines_and_plateaus() {
+        let input = "5\n\n5\n4\n# calibrating\n3\n2\n";
+        let stats = stats_from(input.as_bytes(), &Readings::default(), &[1], |e| {
+            panic!("{}", e)
+        })
+        .unwrap();
+        let run = Run {
+            deeper: false,
+            length: 3,
+            start_line: 3,
+            end_line: 7,
+        };
+        assert_eq!(stats[0].longest_run(), Some(run));
+        assert_eq!(stats[0].plateaus(), 1);
+        assert!(stats[0].deltas().eq([(-1.0, 3), (0.0, 1)]));
+    }
+
+    #[test]
+    fn test_many_deltas() {
+        let input = (0..100_000u64)
+            .map(|i| format!("{}\n", i * i))
+            .collect::<String>();
+        let stats = stats_from(input.as_bytes(), &Readings::default(), &[1], |e| {
+            panic!("{}", e)
+        })
+        .unwrap();
+        assert!(stats[0].deltas().count() <= 1024);
+        assert_eq!(stats[0].deltas().map(|d| d.1).sum::<usize>(), 99_999);
+        let histogram = stats[0].histogram(10);
+        assert_eq!((histogram[0].0, histogram[9].1), (1.0, 200_000.0));
+        assert_eq!(histogram.iter().map(|b| b.2).sum::<usize>(), 99_999);
+        assert!(histogram.iter().all(|b| (9_800..=10_200).contains(&b.2)));
+    }
+
+    #[test]
+    fn test_far_deltas() {
+        let input = "0\n100000000000000000000000000000000000\n0\n";
+        let stats = stats_from(input.as_bytes(), &Readings::default(), &[1], |e| {
+            panic!("{}", e)
+        })
+        .unwrap();
+        assert_eq!(stats[0].deltas().map(|d| d.1).sum::<usize>(), 2);
+        let histogram = stats[0].histogram(10);
+        assert_eq!((histogram[0].0, histogram[0].2), (-1e35, 1));
+        assert_eq!(histogram.last().unwrap().2, 1);
+
+        let readings = Readings {
+            signed: true,
+            decimal: true,
+        };
+        let input = "1e308\n-1e308\n1e308\n";
+        let stats = stats_from(input.as_bytes(), &readings, &[1], |e| panic!("{}", e)).unwrap();
+        assert_eq!(stats[0].histogram(2).iter().map(|b| b.2).sum::<usize>(), 2);
+    }
+
+    #[test]
+    fn test_decimal_histogram() {
+        let readings = Readings {
+            signed: true,
+            decimal: true,
+        };
+        let input = "-1.5\n-1\n0.25\n0\n";
+        let stats = stats_from(input.as_bytes(), &readings, &[1], |e| panic!("{}", e)).unwrap();
+        assert_eq!(
+            stats[0].largest_jump(),
+            Some(Jump {
+                delta: 1.25,
+                line: 3
+            })
+        );
+        assert_eq!(
+            stats[0].histogram(3),
+            [(-0.25, 0.25, 1), (0.25, 0.75, 1), (0.75, 1.25, 1)]
+        );
+        assert!(stats[0]
+            .to_string()
+            .contains("  largest jump: +1.25 at line 3"));
+    }
+}
@@ -0,0 +1,353 @@
+//! Counting increases straight from a reader, for reports too big to load.
+
+use std::{
+    cmp::Ordering, collections::VecDeque, error::Error, io::BufRead, num::IntErrorKind, str,
+};
+
+use aoc_common::{parse_at, ParseError, Solution};
+
+use crate::Day01;
+
+/// One measurement. Whole measurements are kept exactly, however large,
+/// decimal ones as an `f64`.
+#[derive(Debug, Clone, Copy)]
+pub enum Depth {
+    /// A measurement of whole readings.
+    Whole(i128),
+    /// A measurement of decimal readings.
+    Decimal(f64),
+}
+
+impl Depth {
+    /// How much deeper `self` is than `other`. Its size may be rounded, its
+    /// sign never is.
+    pub fn minus(self, other: Depth) -> f64 {
+        match (self, other) {
+            (Depth::Whole(a), Depth::Whole(b)) => match a.checked_sub(b) {
+                Some(delta) => delta as f64,
+                // only far apart depths of opposite signs overflow
+                None => a as f64 - b as f64,
+            },
+            (a, b) => a.as_f64() - b.as_f64(),
+        }
+    }
+
+    fn as_f64(self) -> f64 {
+        match self {
+            Depth::Whole(d) => d as f64,
+            Depth::Decimal(d) => d,
+        }
+    }
+}
+
+impl PartialEq for Depth {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Depth {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.minus(*other).partial_cmp(&0.0)
+    }
+}
+
+/// The last `size` measurements, enough to compare each window of `size`
+/// measurements with the one before.
+///
+/// Two windows in a row share all but their first and last measurement, so
+/// the difference of their sums is the difference of those two.
+#[derive(Debug, Clone)]
+pub(crate) struct Window {
+    size: usize,
+    last: VecDeque<Depth>,
+}
+
+impl Window {
+    pub(crate) fn new(size: usize) -> Self {
+        assert!(size > 0, "a window holds at least one measurement");
+        Self {
+            size,
+            last: VecDeque::with_capacity(size),
+        }
+    }
+
+    /// Moves on to the window ending in `depth`, giving how much deeper it
+    /// is than the window before, if there was a full window before.
+    pub(crate) fn slide(&mut self, depth: Depth) -> Option<f64> {
+        let leaving = match self.last.len() == self.size {
+            true => self.last.pop_front(),
+            false => None,
+        };
+        self.last.push_back(depth);
+        leaving.map(|leaving| depth.minus(leaving))
+    }
+}
+
+/// Counts the windows of `size` measurements whose sum is larger than the
+/// sum of the window before, one measurement at a time, keeping only the
+/// last `size` measurements.
+#[derive(Debug, Clone)]
+pub struct WindowCounter {
+    window: Window,
+    increases: usize,
+}
+
+impl WindowCounter {
+    /// # Panics
+    ///
+    /// When `size` is 0.
+    pub fn new(size: usize) -> Self {
+        Self {
+            window: Window::new(size),
+            increases: 0,
+        }
+    }
+
+    /// Adds the next measurement.
+    pub fn push(&mut self, depth: Depth) {
+        if self.window.slide(depth).is_some_and(|delta| delta > 0.0) {
+            self.increases += 1;
+        }
+    }
+
+    /// How many windows were deeper than the one before so far.
+    pub fn increases(&self) -> usize {
+        self.increases
+    }
+}
+
+/// Which measurements the instruments may send. By default only the whole,
+/// non-negative depths of the puzzle are accepted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Readings {
+    /// Accept negative measurements, like altitudes.
+    pub signed: bool,
+    /// Accept measurements with a fractional part, like `12.5`.
+    pub decimal: bool,
+}
+
+impl Readings {
+    /// The measurement on line `number`, `None` when the line is blank or a
+    /// `#` comment.
+    pub fn parse(&self, line: &str, number: usize) -> Result<Option<Depth>, ParseError> {
+        let token = line.trim();
+        if token.is_empty() || token.starts_with('#') {
+            return Ok(None);
+        }
+        self.parse_token(line, token)
+            .map(Some)
+            .map_err(|e| e.on_line(number))
+    }
+
+    /// Like [`Readings::parse`], for a line that may not be UTF-8.
+    pub fn parse_bytes(&self, line: &[u8], number: usize) -> Result<Option<Depth>, ParseError> {
+        match str::from_utf8(line) {
+            Ok(line) => self.parse(line, number),
+            Err(e) => {
+                let valid = str::from_utf8(&line[..e.valid_up_to()]).expect("checked above");
+                Err(ParseError::new(
+                    Day01::DAY,
+                    number,
+                    valid.chars().count() + 1,
+                    "expected UTF-8 text",
+                ))
+            }
+        }
+    }
+
+    fn parse_token(&self, line: &str, token: &str) -> Result<Depth, ParseError> {
+        let error = |description: &str| {
+            Err(ParseError::at(
+                Day01::DAY,
+                line,
+                token,
+                format!("{} `{}`", description, token),
+            ))
+        };
+        let depth = match self.decimal {
+            true => match parse_at::<f64>(Day01::DAY, line, token)? {
+                depth if depth.is_finite() => Depth::Decimal(depth),
+                _ => return error("not a measurement"),
+            },
+            false => match token.parse::<i128>() {
+                Ok(depth) => Depth::Whole(depth),
+                Err(e)
+                    if *e.kind() == IntErrorKind::InvalidDigit
+                        && token.parse::<f64>().is_ok_and(f64::is_finite) =>
+                {
+                    return error("decimal measurement without decimal readings")
+                }
+                Err(_) => parse_at(Day01::DAY, line, token).map(Depth::Whole)?,
+            },
+        };
+        if depth < Depth::Whole(0) && !self.signed {
+            return error("negative measurement without signed readings");
+        }
+        Ok(depth)
+    }
+}
+
+/// Calls `f` with the line number and measurement of every line of `reader`
+/// that isn't blank or a comment. Lines that aren't measurements, or not
+/// even UTF-8, go to `on_invalid` and are skipped.
+pub fn read_depths<R, F, E>(
+    mut reader: R,
+    readings: &Readings,
+    mut f: F,
+    mut on_invalid: E,
+) -> Result<(), Box<dyn Error>>
+where
+    R: BufRead,
+    F: FnMut(usize, Depth),
+    E: FnMut(ParseError),
+{
+    let mut line = Vec::new();
+    let mut number = 0;
+    while reader.read_until(b'\n', &mut line)? > 0 {
+        number += 1;
+        match readings.parse_bytes(&line, number) {
+            Ok(Some(depth)) => f(number, depth),
+            Ok(None) => {}
+            Err(e) => on_invalid(e),
+        }
+        line.clear();
+    }
+    Ok(())
+}
+
+/// Reads one measurement per line from `reader`, counting the increases of
+/// every window size in `sizes` in a single pass. Invalid lines go to
+/// `on_invalid`.
+pub fn increases_from<R, E>(
+    reader: R,
+    readings: &Readings,
+    sizes: &[usize],
+    on_invalid: E,
+) -> Result<Vec<usize>, Box<dyn Error>>
+where
+    R: BufRead,
+    E: FnMut(ParseError),
+{
+    let mut counters = sizes
+        .iter()
+        .map(|&k| WindowCounter::new(k))
+        .collect::<Vec<_>>();
+    read_depths(
+        reader,
+        readings,
+        |_, depth| counters.iter_mut().for_each(|c| c.push(depth)),
+        on_invalid,
+    )?;
+    Ok(counters.iter().map(WindowCounter::increases).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{generate, increases, parse_input};
+
+    const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    fn count(input: &str, readings: Readings, sizes: &[usize]) -> (Vec<usize>, Vec<ParseError>) {
+        let mut invalid = Vec::new();
+        let counts =
+            increases_from(input.as_bytes(), &readings, sizes, |e| invalid.push(e)).unwrap();
+        (counts, invalid)
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(
+            count(EXAMPLE, Readings::default(), &[1, 3]),
+            (vec![7, 5], vec![])
+        );
+    }
+
+    #[test]
+    fn test_any_size() {
+        let input = generate::input(&mut StdRng::seed_from_u64(1), 200);
+        let depths = parse_input(&input, &Readings::default()).unwrap();
+        let sizes = [1, 2, 3, 5, 50, 500];
+        let expected = sizes.map(|k| increases(&depths, k));
+        assert_eq!(count(&input, Readings::default(), &sizes).0, expected);
+    }
+
+    #[test]
+    fn test_invalid_lines() {
+        let (counts, invalid) = count("1\n\n2\nx\n3\n", Readings::default(), &[1]);
+        assert_eq!(counts, [2]);
+        assert_eq!(
+            invalid.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["day 1, line 4, column 1: invalid number `x`: invalid digit found in string"]
+        );
+    }
+
+    #[test]
+    fn test_readings() {
+        let input = "# altitude\n-3\n  -1.5\n2\n#0\n2.25\nnan\n";
+        let lines = |readings| {
+            let (_, invalid) = count(input, readings, &[1]);
+            invalid.iter().map(|e| e.line).collect::<Vec<_>>()
+        };
+        assert_eq!(lines(Readings::default()), [2, 3, 6, 7]);
+        let signed = Readings {
+            signed: true,
+            decimal: false,
+        };
+        assert_eq!(lines(signed), [3, 6, 7]);
+        let decimal = Readings {
+            signed: false,
+            decimal: true,
+        };
+        assert_eq!(lines(decimal), [2, 3, 7]);
+        let both = Readings {
+            signed: true,
+            decimal: true,
+        };
+        assert_eq!(count(input, both, &[1]).0, [3]);
+        assert_eq!(lines(both), [7]);
+        assert_eq!(
+            both.parse("  -1.5e1\n", 9).map(|d| d.unwrap()),
+            Ok(Depth::Decimal(-15.0))
+        );
+        let e = Readings::default().parse("  -3\n", 2).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "day 1, line 2, column 3: negative measurement without signed readings `-3`"
+        );
+    }
+
+    #[test]
+    fn test_whole_readings_are_exact() {
+        let input = "9007199254740992\n9007199254740993\n18446744073709551615\n";
+        assert_eq!(count(input, Readings::default(), &[1]), (vec![2], vec![]));
+        let signed = Readings {
+            signed: true,
+            decimal: false,
+        };
+        let input = "-170141183460469231731687303715884105728\n\
+                     170141183460469231731687303715884105727\n";
+        assert_eq!(count(input, signed, &[1]).0, [1]);
+        let (_, invalid) = count("1000000000000000000000000000000000000000\n", signed, &[1]);
+        assert_eq!(
+            invalid[0].description,
+            "invalid number `1000000000000000000000000000000000000000`: \
+             number too large to fit in target type"
+        );
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let mut invalid = Vec::new();
+        let input: &[u8] = b"1\n2\xff3\n3\n";
+        let counts = increases_from(input, &Readings::default(), &[1], |e| invalid.push(e));
+        assert_eq!(counts.unwrap(), [1]);
+        assert_eq!(
+            invalid.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["day 1, line 2, column 2: expected UTF-8 text"]
+        );
+    }
+}
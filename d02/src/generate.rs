@@ -0,0 +1,24 @@
+//! Random submarine courses.
+
+use rand::{Rng, RngExt};
+
+/// `size` commands. `up` never rises above the surface.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut depth = 0;
+    (0..size)
+        .map(|_| {
+            let n = rng.random_range(1..10);
+            match rng.random_range(0..3) {
+                0 => format!("forward {}\n", n),
+                1 if depth >= n => {
+                    depth -= n;
+                    format!("up {}\n", n)
+                }
+                _ => {
+                    depth += n;
+                    format!("down {}\n", n)
+                }
+            }
+        })
+        .collect()
+}
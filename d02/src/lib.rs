@@ -0,0 +1,108 @@
+//! Day 2: Dive!, steering the submarine with a planned course.
+#![warn(missing_docs)]
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    path::Path,
+    str::FromStr,
+};
+
+use aoc_common::{parse_at, read_input, ParseError, Solution};
+use model::{AimModel, DepthModel, RunError, SubmarineModel};
+
+pub mod generate;
+pub mod model;
+pub mod plan;
+pub mod script;
+pub mod trajectory;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// One step of the course, `up 3` and so on.
+pub enum Command {
+    /// Decreases the depth, or the aim in part 2.
+    Up(usize),
+    /// Increases the depth, or the aim in part 2.
+    Down(usize),
+    /// Moves forward, and in part 2 also dives by the aim times the distance.
+    Forward(usize),
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, len) = s.trim().split_once(' ').ok_or_else(|| {
+            ParseError::at(Day02::DAY, s, s.trim(), "expected `<direction> <length>`")
+        })?;
+        let len: usize = parse_at(Day02::DAY, s, len.trim())?;
+        match direction {
+            "forward" => Ok(Self::Forward(len)),
+            "up" => Ok(Self::Up(len)),
+            "down" => Ok(Self::Down(len)),
+            n => Err(ParseError::at(
+                Day02::DAY,
+                s,
+                direction,
+                format!("unknown direction `{}`", n),
+            )),
+        }
+    }
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Up(n) => write!(f, "up {}", n),
+            Command::Down(n) => write!(f, "down {}", n),
+            Command::Forward(n) => write!(f, "forward {}", n),
+        }
+    }
+}
+
+/// Reads and parses the course at `path`.
+pub fn parse_input<P: AsRef<Path>>(path: P) -> Result<Vec<Command>, Box<dyn Error>> {
+    Ok(parse_text(&read_input(path)?)?)
+}
+
+/// One command per line, or a [`script`] that expands to them.
+pub fn parse_text(input: &str) -> Result<Vec<Command>, ParseError> {
+    input.parse::<script::Script>()?.commands()
+}
+
+/// The horizontal position times the depth, with `up` and `down` changing the depth.
+pub fn part1(input: &[Command]) -> Result<u128, RunError> {
+    DepthModel::default()
+        .run(input.iter().copied())
+        .map(|p| p.product())
+}
+
+/// The horizontal position times the depth, with `up` and `down` changing the aim.
+pub fn part2(input: &[Command]) -> Result<u128, RunError> {
+    AimModel::default()
+        .run(input.iter().copied())
+        .map(|a| a.position.product())
+}
+
+/// The [`Solution`] of day 2.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Command>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_text(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input)?)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input)?)
+    }
+}
@@ -1,69 +1,98 @@
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader},
-    path::Path,
-    str::FromStr,
+use std::{env, error::Error, io, process::ExitCode};
+
+use aoc_common::{InputSource, Solution};
+use d02::{
+    model::{AimModel, DepthModel, Position, SubmarineModel, Surface},
+    parse_text,
+    plan::{Limits, Plan},
+    trajectory::{trajectory, write_csv},
+    Day02,
 };
 
-#[derive(Debug, Copy, Clone)]
-enum Command {
-    Up(usize),
-    Down(usize),
-    Forward(usize),
+/// `d02 [<input>] [--trajectory <depth | aim>] [--clamp]`, printing both
+/// parts, or with `--trajectory` the state after every command of that model
+/// as CSV. Rising above the surface is an error unless `--clamp` keeps the
+/// submarine at the surface.
+///
+/// `d02 --plan <depth | aim> <horizontal>,<depth> [--max-step <n>] [--max-commands <n>]`
+/// prints one of the shortest courses to that position instead.
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
 }
 
-impl FromStr for Command {
-    type Err = &'static str;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (direction, len) = s.trim().split_once(" ").ok_or("Malformated")?;
-        let len: usize = len.parse().map_err(|_| "Not a number")?;
-        match direction {
-            "forward" => Ok(Self::Forward(len)),
-            "up" => Ok(Self::Up(len)),
-            "down" => Ok(Self::Down(len)),
-            n => {
-                dbg!("this is strange: {}", n);
-                Err("Unknown direction")
+fn run() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1);
+    let mut input = None;
+    let mut model = None;
+    let mut surface = Surface::Fail;
+    let mut plan = None;
+    let mut limits = Limits::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--trajectory" => {
+                model = Some(args.next().ok_or("`--trajectory` needs `depth` or `aim`")?)
+            }
+            "--clamp" => surface = Surface::Clamp,
+            "--plan" => {
+                let model = args.next().ok_or("`--plan` needs `depth` or `aim`")?;
+                let target = args.next().ok_or("`--plan` needs `<horizontal>,<depth>`")?;
+                plan = Some((model, target));
             }
+            "--max-step" => limits.max_step = Some(number(&arg, args.next())?),
+            "--max-commands" => limits.max_commands = Some(number(&arg, args.next())?),
+            _ if input.is_none() => input = Some(InputSource::from_arg(&arg)),
+            _ => return Err(format!("unexpected argument `{}`", arg).into()),
         }
     }
-}
-
-fn parse_input<P: AsRef<Path>>(path: P) -> io::Result<Vec<Command>> {
-    let reader = BufReader::new(File::open(path)?);
-    reader.lines().map(|l| Ok(l?.parse().unwrap())).collect()
-}
-
-fn part1(input: &[Command]) {
-    let mut horizontal = 0;
-    let mut vertical = 0;
-    input.iter().for_each(|n| match n {
-        Command::Up(n) => vertical -= n,
-        Command::Down(n) => vertical += n,
-        Command::Forward(n) => horizontal += n,
-    });
-    println!("Part 1: {}", horizontal * vertical);
-}
-
-fn part2(input: &[Command]) {
-    let mut aim = 0;
-    let mut horizontal = 0;
-    let mut vertical = 0;
-    input.iter().for_each(|n| match n {
-        Command::Up(n) => aim -= n,
-        Command::Down(n) => aim += n,
-        Command::Forward(n) => {
-            horizontal += n;
-            vertical += aim * n
+    if let Some((model, target)) = plan {
+        let (horizontal, depth) = target
+            .split_once(',')
+            .ok_or_else(|| format!("expected `<horizontal>,<depth>`, found `{}`", target))?;
+        let target = Position {
+            horizontal: number("--plan", Some(horizontal.to_string()))?,
+            depth: number("--plan", Some(depth.to_string()))?,
+        };
+        let course = match model.as_str() {
+            "depth" => DepthModel::default().plan(target, &limits)?,
+            "aim" => AimModel::default().plan(target, &limits)?,
+            _ => {
+                return Err(format!("unknown model `{}`, expected `depth` or `aim`", model).into())
+            }
+        };
+        course.iter().for_each(|command| println!("{}", command));
+        return Ok(());
+    }
+    let source = input
+        .or_else(InputSource::from_env)
+        .unwrap_or_else(|| InputSource::Path("input.txt".into()));
+    let input = parse_text(&source.read(Day02::DAY)?)?;
+    let out = io::stdout().lock();
+    match model.as_deref() {
+        None => {
+            let depth = DepthModel { surface }.run(input.iter().copied())?;
+            let aim = AimModel { surface }.run(input.iter().copied())?;
+            println!("Part 1: {}", depth.product());
+            println!("Part 2: {}", aim.position.product());
         }
-    });
-    println!("Part 2: {}", horizontal * vertical);
+        Some("depth") => write_csv(out, trajectory(&DepthModel { surface }, input))?,
+        Some("aim") => write_csv(out, trajectory(&AimModel { surface }, input))?,
+        Some(model) => {
+            return Err(format!("unknown model `{}`, expected `depth` or `aim`", model).into())
+        }
+    }
+    Ok(())
 }
 
-fn main() -> io::Result<()> {
-    let input = parse_input("input.txt")?;
-    part1(&input);
-    part2(&input);
-    Ok(())
+/// The number after `flag`.
+fn number(flag: &str, value: Option<String>) -> Result<usize, String> {
+    let value = value.ok_or_else(|| format!("`{}` needs a number", flag))?;
+    value
+        .parse()
+        .map_err(|_| format!("`{}` needs a number, found `{}`", flag, value))
 }
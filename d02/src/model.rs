@@ -0,0 +1,320 @@
+//! How the submarine moves with each command.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::Command;
+
+/// The physics of a submarine, stepped one command at a time.
+///
+/// The two parts of the puzzle are [`DepthModel`] and [`AimModel`], others
+/// can keep whatever state they need, like a drift or a depth limit.
+pub trait SubmarineModel {
+    /// Everything the model keeps track of.
+    type State;
+
+    /// The state before the first command.
+    fn initial(&self) -> Self::State;
+
+    /// The state after `command`, or why the model can't carry it out.
+    fn step(&self, state: Self::State, command: Command) -> Result<Self::State, Fault>;
+
+    /// The state after every command of `commands`, from the initial state.
+    fn run<I>(&self, commands: I) -> Result<Self::State, RunError>
+    where
+        I: IntoIterator<Item = Command>,
+        Self: Sized,
+    {
+        commands
+            .into_iter()
+            .enumerate()
+            .try_fold(self.initial(), |state, (index, command)| {
+                self.step(state, command).map_err(|fault| RunError {
+                    step: index + 1,
+                    command,
+                    fault,
+                })
+            })
+    }
+}
+
+/// One of the numbers a model keeps track of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gauge {
+    /// How far forward the submarine went.
+    Horizontal,
+    /// How far below the surface it is.
+    Depth,
+    /// Where it is heading.
+    Aim,
+}
+
+impl Display for Gauge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Gauge::Horizontal => "horizontal position",
+            Gauge::Depth => "depth",
+            Gauge::Aim => "aim",
+        })
+    }
+}
+
+/// Why a model can't carry out a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    /// The gauge would go below 0, above the surface for the depth.
+    Surfaced(Gauge),
+    /// The gauge would go past `usize::MAX`.
+    Overflow(Gauge),
+}
+
+/// A run that stopped at a command the model couldn't carry out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunError {
+    /// Where the command is in the course, from 1 like the
+    /// [`Sample::step`](crate::trajectory::Sample::step) it would have given.
+    pub step: usize,
+    /// The command.
+    pub command: Command,
+    /// What went wrong.
+    pub fault: Fault,
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "command {} (`{}`) ", self.step, self.command)?;
+        match self.fault {
+            Fault::Surfaced(Gauge::Depth) => write!(f, "takes the submarine above the surface"),
+            Fault::Surfaced(gauge) => write!(f, "takes the {} below 0", gauge),
+            Fault::Overflow(gauge) => write!(f, "overflows the {}", gauge),
+        }
+    }
+}
+
+impl Error for RunError {}
+
+/// What the built-in models do when the depth or the aim would go below 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Surface {
+    /// Stop the run with a [`RunError`].
+    #[default]
+    Fail,
+    /// Stay at 0.
+    Clamp,
+}
+
+/// `value + n`.
+fn add(value: usize, n: usize, gauge: Gauge) -> Result<usize, Fault> {
+    value.checked_add(n).ok_or(Fault::Overflow(gauge))
+}
+
+/// `value - n`, which can't go below 0.
+fn subtract(value: usize, n: usize, gauge: Gauge, surface: Surface) -> Result<usize, Fault> {
+    match (value.checked_sub(n), surface) {
+        (Some(value), _) => Ok(value),
+        (None, Surface::Clamp) => Ok(0),
+        (None, Surface::Fail) => Err(Fault::Surfaced(gauge)),
+    }
+}
+
+/// Where the submarine is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Position {
+    /// How far forward it went.
+    pub horizontal: usize,
+    /// How far below the surface it is.
+    pub depth: usize,
+}
+
+impl Position {
+    /// The horizontal position times the depth, the puzzle's answer. It is
+    /// wide enough for any two positions.
+    pub fn product(&self) -> u128 {
+        self.horizontal as u128 * self.depth as u128
+    }
+}
+
+/// Where the submarine is and where it is heading.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Aimed {
+    /// Where the submarine is.
+    pub position: Position,
+    /// How much deeper it gets with every step forward.
+    pub aim: usize,
+}
+
+/// What the instruments show about a state, for the
+/// [`trajectory`](crate::trajectory) of a run.
+pub trait Gauges {
+    /// Where the submarine is.
+    fn position(&self) -> Position;
+
+    /// Where it is heading, for models with an aim.
+    fn aim(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl Gauges for Position {
+    fn position(&self) -> Position {
+        *self
+    }
+}
+
+impl Gauges for Aimed {
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn aim(&self) -> Option<usize> {
+        Some(self.aim)
+    }
+}
+
+/// Part 1, where `up` and `down` change the depth.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DepthModel {
+    /// What rising above the surface does.
+    pub surface: Surface,
+}
+
+impl SubmarineModel for DepthModel {
+    type State = Position;
+
+    fn initial(&self) -> Position {
+        Position::default()
+    }
+
+    fn step(&self, mut state: Position, command: Command) -> Result<Position, Fault> {
+        match command {
+            Command::Up(n) => state.depth = subtract(state.depth, n, Gauge::Depth, self.surface)?,
+            Command::Down(n) => state.depth = add(state.depth, n, Gauge::Depth)?,
+            Command::Forward(n) => state.horizontal = add(state.horizontal, n, Gauge::Horizontal)?,
+        }
+        Ok(state)
+    }
+}
+
+/// Part 2, where `up` and `down` change the aim and `forward` dives by the
+/// aim times the distance.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AimModel {
+    /// What aiming above the surface does. The depth itself can't drop
+    /// while the aim is at least 0.
+    pub surface: Surface,
+}
+
+impl SubmarineModel for AimModel {
+    type State = Aimed;
+
+    fn initial(&self) -> Aimed {
+        Aimed::default()
+    }
+
+    fn step(&self, mut state: Aimed, command: Command) -> Result<Aimed, Fault> {
+        match command {
+            Command::Up(n) => state.aim = subtract(state.aim, n, Gauge::Aim, self.surface)?,
+            Command::Down(n) => state.aim = add(state.aim, n, Gauge::Aim)?,
+            Command::Forward(n) => {
+                let dive = state
+                    .aim
+                    .checked_mul(n)
+                    .ok_or(Fault::Overflow(Gauge::Depth))?;
+                state.position.horizontal = add(state.position.horizontal, n, Gauge::Horizontal)?;
+                state.position.depth = add(state.position.depth, dive, Gauge::Depth)?;
+            }
+        }
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_text;
+
+    const EXAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+    /// Part 1 in a channel that is only `max` deep.
+    struct Channel {
+        max: usize,
+    }
+
+    impl SubmarineModel for Channel {
+        type State = Position;
+
+        fn initial(&self) -> Position {
+            Position::default()
+        }
+
+        fn step(&self, state: Position, command: Command) -> Result<Position, Fault> {
+            let mut state = DepthModel::default().step(state, command)?;
+            state.depth = state.depth.min(self.max);
+            Ok(state)
+        }
+    }
+
+    #[test]
+    fn test_models() {
+        let commands = parse_text(EXAMPLE).unwrap();
+        let position = Position {
+            horizontal: 15,
+            depth: 10,
+        };
+        assert_eq!(
+            DepthModel::default().run(commands.iter().copied()),
+            Ok(position)
+        );
+        let aimed = AimModel::default().run(commands.iter().copied()).unwrap();
+        assert_eq!((aimed.position.product(), aimed.aim), (900, 10));
+        let position = Channel { max: 4 }.run(commands).unwrap();
+        assert_eq!((position.horizontal, position.depth), (15, 4));
+        let far = Position {
+            horizontal: 5_000_000_000,
+            depth: 5_000_000_000,
+        };
+        assert_eq!(far.product(), 25_000_000_000_000_000_000);
+    }
+
+    #[test]
+    fn test_surfacing() {
+        let commands = parse_text("down 2\nforward 3\nup 5\nforward 1\n").unwrap();
+        let e = DepthModel::default()
+            .run(commands.iter().copied())
+            .unwrap_err();
+        assert_eq!(
+            e,
+            RunError {
+                step: 3,
+                command: Command::Up(5),
+                fault: Fault::Surfaced(Gauge::Depth),
+            }
+        );
+        assert_eq!(
+            e.to_string(),
+            "command 3 (`up 5`) takes the submarine above the surface"
+        );
+        let e = AimModel::default()
+            .run(commands.iter().copied())
+            .unwrap_err();
+        assert_eq!(e.to_string(), "command 3 (`up 5`) takes the aim below 0");
+
+        let clamped = DepthModel {
+            surface: Surface::Clamp,
+        };
+        let position = clamped.run(commands.iter().copied()).unwrap();
+        assert_eq!((position.horizontal, position.depth), (4, 0));
+        let clamped = AimModel {
+            surface: Surface::Clamp,
+        };
+        let aimed = clamped.run(commands).unwrap();
+        assert_eq!((aimed.position.depth, aimed.aim), (6, 0));
+
+        let e = DepthModel::default()
+            .run([Command::Down(usize::MAX), Command::Down(1)])
+            .unwrap_err();
+        assert_eq!(e.fault, Fault::Overflow(Gauge::Depth));
+    }
+}
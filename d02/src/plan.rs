@@ -0,0 +1,349 @@
+//! Courses that take the submarine to a given position, the inverse of a run.
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    error::Error,
+    fmt::{self, Display},
+    iter,
+};
+
+use crate::{
+    model::{AimModel, DepthModel, Position},
+    Command,
+};
+
+/// What the courses of [`Plan::plan`] must keep to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// The longest a single command may be.
+    pub max_step: Option<usize>,
+    /// The most commands a course may have.
+    pub max_commands: Option<usize>,
+}
+
+/// Why there is no course to a target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanError {
+    /// The longest command is 0, which goes nowhere.
+    ZeroStep,
+    /// The aim model only gets deeper while going forward.
+    Unreachable,
+    /// Every course takes more than this many commands.
+    TooLong(usize),
+    /// The search gave up after this many positions, with long commands
+    /// that lead to too many of them.
+    TooHard(usize),
+}
+
+impl Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanError::ZeroStep => write!(f, "the longest command must be at least 1"),
+            PlanError::Unreachable => write!(f, "nothing gets deeper without going forward"),
+            PlanError::TooLong(n) => write!(f, "every course takes more than {} commands", n),
+            PlanError::TooHard(n) => {
+                write!(f, "gave up looking for a course after {} positions", n)
+            }
+        }
+    }
+}
+
+impl Error for PlanError {}
+
+/// A model that can find the way to a position.
+pub trait Plan {
+    /// One of the shortest courses that ends at `target`, never going above
+    /// the surface.
+    fn plan(&self, target: Position, limits: &Limits) -> Result<Vec<Command>, PlanError>;
+}
+
+/// The most commands a course of [`DepthModel`] has when the [`Limits`]
+/// don't say, as it only gets as long as the target is far.
+const PLAN_COMMANDS: usize = 1_000_000;
+
+/// The longest command allowed, which can't be 0.
+fn max_step(limits: &Limits) -> Result<usize, PlanError> {
+    match limits.max_step {
+        Some(0) => Err(PlanError::ZeroStep),
+        step => Ok(step.unwrap_or(usize::MAX)),
+    }
+}
+
+/// `total` in as few commands of at most `step` as there can be.
+fn split(
+    total: usize,
+    step: usize,
+    command: fn(usize) -> Command,
+) -> impl Iterator<Item = Command> {
+    let mut left = total;
+    iter::from_fn(move || {
+        let len = left.min(step);
+        left -= len;
+        (len > 0).then(|| command(len))
+    })
+}
+
+/// `commands`, unless there are too many of them.
+fn within(commands: Vec<Command>, limits: &Limits) -> Result<Vec<Command>, PlanError> {
+    match limits.max_commands {
+        Some(max) if commands.len() > max => Err(PlanError::TooLong(max)),
+        _ => Ok(commands),
+    }
+}
+
+impl Plan for DepthModel {
+    fn plan(&self, target: Position, limits: &Limits) -> Result<Vec<Command>, PlanError> {
+        let step = max_step(limits)?;
+        let max = limits.max_commands.unwrap_or(PLAN_COMMANDS);
+        let commands = target
+            .horizontal
+            .div_ceil(step)
+            .saturating_add(target.depth.div_ceil(step));
+        if commands > max {
+            return Err(PlanError::TooLong(max));
+        }
+        Ok(split(target.horizontal, step, Command::Forward)
+            .chain(split(target.depth, step, Command::Down))
+            .collect())
+    }
+}
+
+impl Plan for AimModel {
+    fn plan(&self, target: Position, limits: &Limits) -> Result<Vec<Command>, PlanError> {
+        let step = max_step(limits)?;
+        let Position { horizontal, depth } = target;
+        if horizontal == 0 {
+            return match depth {
+                0 => Ok(Vec::new()),
+                _ => Err(PlanError::Unreachable),
+            };
+        }
+        if step < horizontal.max(depth) {
+            return search(target, step, limits.max_commands);
+        }
+        // one forward, a dive and a forward do it when the lengths don't matter
+        let commands = match (depth, depth % horizontal) {
+            (0, _) => vec![Command::Forward(horizontal)],
+            (_, 0) => vec![
+                Command::Down(depth / horizontal),
+                Command::Forward(horizontal),
+            ],
+            _ => vec![
+                Command::Forward(horizontal - 1),
+                Command::Down(depth),
+                Command::Forward(1),
+            ],
+        };
+        within(commands, limits)
+    }
+}
+
+/// The most commands [`search`] looks for when the [`Limits`] don't say.
+const SEARCH_COMMANDS: usize = 10_000;
+
+/// The most positions [`search`] goes through, which bounds its memory when
+/// long commands lead to many positions at once.
+const SEARCH_POSITIONS: usize = 1_000_000;
+
+/// How far there is still to go forward, and how much deeper the downs from
+/// here on still have to take the submarine. A down of `k` with `r` still
+/// to go ends up `k * r` deeper, whatever the aim already is. Going up never
+/// makes a course shorter, so the courses only go down.
+type Leg = (usize, usize);
+
+/// A* over the [`Leg`]s from the target to `(0, 0)`, estimating the
+/// commands left by the forwards needed and the downs needed if they all
+/// came right away. Without `max_commands` it gives up after
+/// [`SEARCH_COMMANDS`], and always after [`SEARCH_POSITIONS`].
+fn search(
+    target: Position,
+    step: usize,
+    max_commands: Option<usize>,
+) -> Result<Vec<Command>, PlanError> {
+    let max_commands = max_commands.unwrap_or(SEARCH_COMMANDS);
+    let estimate = |(r, d): Leg| match (r, d) {
+        (_, 0) => Some(r.div_ceil(step)),
+        (0, _) => None,
+        _ => Some(r.div_ceil(step) + d.div_ceil(step.saturating_mul(r))),
+    };
+    let start = (target.horizontal, target.depth);
+    let mut commands = HashMap::from([(start, 0)]);
+    let mut from = HashMap::<Leg, (Leg, Command)>::new();
+    let mut queue = BinaryHeap::new();
+    let mut positions = 0;
+    if let Some(estimate) = estimate(start) {
+        queue.push(Reverse((estimate, Reverse(0), start)));
+    }
+    while let Some(Reverse((_, Reverse(n), leg))) = queue.pop() {
+        if n > commands[&leg] {
+            continue;
+        }
+        let (r, d) = leg;
+        if leg == (0, 0) {
+            let mut course = Vec::with_capacity(n);
+            let mut leg = leg;
+            while let Some(&(previous, command)) = from.get(&leg) {
+                course.push(command);
+                leg = previous;
+            }
+            course.reverse();
+            return Ok(course);
+        }
+        let downs = (1..=step.min(d.checked_div(r).unwrap_or(0)))
+            .map(|k| ((r, d - k * r), Command::Down(k)));
+        let forwards = (1..=step.min(r)).map(|s| ((r - s, d), Command::Forward(s)));
+        for (next, command) in downs.chain(forwards) {
+            let Some(estimate) = estimate(next) else {
+                continue;
+            };
+            if n + 1 + estimate > max_commands {
+                continue;
+            }
+            if commands.get(&next).is_none_or(|&m| n + 1 < m) {
+                positions += 1;
+                if positions > SEARCH_POSITIONS {
+                    return Err(PlanError::TooHard(SEARCH_POSITIONS));
+                }
+                commands.insert(next, n + 1);
+                from.insert(next, (leg, command));
+                queue.push(Reverse((n + 1 + estimate, Reverse(n + 1), next)));
+            }
+        }
+    }
+    Err(PlanError::TooLong(max_commands))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::*;
+    use crate::model::SubmarineModel;
+
+    fn target(horizontal: usize, depth: usize) -> Position {
+        Position { horizontal, depth }
+    }
+
+    fn limited(step: usize) -> Limits {
+        Limits {
+            max_step: Some(step),
+            max_commands: None,
+        }
+    }
+
+    /// The fewest commands to every depth up to `max_depth` at `horizontal`
+    /// in the aim model, trying every course, going up included.
+    fn fewest(horizontal: usize, max_depth: usize, step: usize) -> HashMap<usize, usize> {
+        let mut seen = HashMap::from([((0, 0, 0), 0)]);
+        let mut queue = VecDeque::from([(0, 0, 0)]);
+        let mut fewest = HashMap::new();
+        while let Some((h, d, aim)) = queue.pop_front() {
+            let n = seen[&(h, d, aim)];
+            if h == horizontal {
+                fewest.entry(d).or_insert(n);
+            }
+            for k in 1..=step {
+                let next = [
+                    Some((h, d, aim + k)),
+                    aim.checked_sub(k).map(|aim| (h, d, aim)),
+                    Some((h + k, d + aim * k, aim)),
+                ];
+                for next in next.into_iter().flatten() {
+                    if next.0 <= horizontal && next.1 <= max_depth && next.2 <= max_depth {
+                        seen.entry(next).or_insert_with(|| {
+                            queue.push_back(next);
+                            n + 1
+                        });
+                    }
+                }
+            }
+        }
+        fewest
+    }
+
+    #[test]
+    fn test_depth_plan() {
+        let course = DepthModel::default()
+            .plan(target(15, 10), &limited(4))
+            .unwrap();
+        assert_eq!(course.len(), 7);
+        assert_eq!(DepthModel::default().run(course), Ok(target(15, 10)));
+    }
+
+    #[test]
+    fn test_aim_plan_is_shortest() {
+        let model = AimModel::default();
+        for step in 1..=4 {
+            for horizontal in 0..=7 {
+                let fewest = fewest(horizontal, 30, step);
+                for depth in 0..=30 {
+                    let course = model.plan(target(horizontal, depth), &limited(step));
+                    let Ok(course) = course else {
+                        assert_eq!(course, Err(PlanError::Unreachable));
+                        assert!(!fewest.contains_key(&depth));
+                        continue;
+                    };
+                    assert_eq!(Some(&course.len()), fewest.get(&depth));
+                    let end = model.run(course.iter().copied()).unwrap();
+                    assert_eq!(end.position, target(horizontal, depth));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_limits() {
+        let model = AimModel::default();
+        assert_eq!(
+            model.plan(target(15, 60), &Limits::default()),
+            Ok(vec![Command::Down(4), Command::Forward(15)])
+        );
+        assert_eq!(
+            model
+                .plan(target(15, 61), &Limits::default())
+                .unwrap()
+                .len(),
+            3
+        );
+        let course = model.plan(target(2000, 1_000_000), &limited(9)).unwrap();
+        assert_eq!(course.len(), 279);
+        assert_eq!(model.run(course).unwrap().position, target(2000, 1_000_000));
+        let limits = Limits {
+            max_step: Some(4),
+            max_commands: Some(4),
+        };
+        assert_eq!(
+            model.plan(target(10, 11), &limits),
+            Err(PlanError::TooLong(4))
+        );
+        assert_eq!(
+            model.plan(target(3, 1_000_000_000_000), &limited(2)),
+            Err(PlanError::TooLong(SEARCH_COMMANDS))
+        );
+        assert_eq!(
+            model.plan(target(1_000_000, 1_000_000_000_000), &limited(999_999)),
+            Err(PlanError::TooHard(SEARCH_POSITIONS))
+        );
+        assert_eq!(
+            model.plan(target(0, 1), &limited(4)),
+            Err(PlanError::Unreachable)
+        );
+        assert_eq!(
+            DepthModel::default().plan(target(1, 1), &limited(0)),
+            Err(PlanError::ZeroStep)
+        );
+        assert_eq!(
+            DepthModel::default().plan(target(usize::MAX, usize::MAX), &limited(1)),
+            Err(PlanError::TooLong(PLAN_COMMANDS))
+        );
+        let limits = Limits {
+            max_step: Some(4),
+            max_commands: Some(6),
+        };
+        assert_eq!(
+            DepthModel::default().plan(target(15, 10), &limits),
+            Err(PlanError::TooLong(6))
+        );
+    }
+}
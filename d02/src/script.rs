@@ -0,0 +1,379 @@
+//! Course scripts, which the route planners write instead of long courses.
+//!
+//! A script is a course with a few additions, one per line:
+//!
+//! ```text
+//! # comments start with `#`, also after a command
+//! forward 5
+//! macro zigzag {
+//!     down 2
+//!     forward 1
+//!     up 2
+//! }
+//! repeat 3 {
+//!     zigzag
+//!     forward +1   # one more than the last `forward`
+//! }
+//! ```
+//!
+//! Macros are defined at the top level before they are used, so they can't
+//! call themselves. `+n` and `-n` change the last length of that direction,
+//! which starts at 0, as the script runs.
+
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_common::{parse_at, ParseError, Solution};
+
+use crate::{Command, Day02};
+
+/// The most commands a script may expand to.
+pub const MAX_COMMANDS: usize = 10_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Forward,
+    Up,
+    Down,
+}
+
+impl Direction {
+    fn command(self, len: usize) -> Command {
+        match self {
+            Direction::Forward => Command::Forward(len),
+            Direction::Up => Command::Up(len),
+            Direction::Down => Command::Down(len),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Length {
+    Exact(usize),
+    By(i64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Step {
+    Move {
+        direction: Direction,
+        length: Length,
+        /// The line and column of the length, for relative lengths that
+        /// drop below 0.
+        at: (usize, usize),
+    },
+    Repeat {
+        times: usize,
+        body: Vec<Step>,
+        /// How many commands one pass of the body expands to.
+        len: u64,
+    },
+    Call(usize),
+}
+
+/// A block that is still open.
+enum Block<'a> {
+    Repeat(usize),
+    Macro(&'a str),
+}
+
+/// A parsed course script, expanded into commands by [`Script::commands`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Script {
+    steps: Vec<Step>,
+    macros: Vec<Vec<Step>>,
+    /// How many commands each macro expands to.
+    lengths: Vec<u64>,
+}
+
+impl FromStr for Script {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut macros = Vec::new();
+        let mut lengths = Vec::new();
+        let mut names = HashMap::new();
+        let mut open = Vec::new();
+        let mut steps = vec![Vec::new()];
+        for (n, line) in s.lines().enumerate() {
+            let code = line.split('#').next().unwrap_or_default();
+            let words = code.split_whitespace().collect::<Vec<_>>();
+            let error = |token: &str, description: String| {
+                ParseError::at(Day02::DAY, line, token, description).on_line(n + 1)
+            };
+            let step = match words.as_slice() {
+                [] => continue,
+                ["}"] => {
+                    let block = open
+                        .pop()
+                        .ok_or_else(|| error(words[0], "`}` without a block".to_string()))?;
+                    let body = steps.pop().expect("a body for every open block");
+                    // macros only call the ones before them, so their lengths are known
+                    let len = count(&body, &lengths);
+                    match block {
+                        (Block::Repeat(times), _) => Step::Repeat { times, body, len },
+                        (Block::Macro(name), _) => {
+                            names.insert(name, macros.len());
+                            macros.push(body);
+                            lengths.push(len);
+                            continue;
+                        }
+                    }
+                }
+                ["repeat", times, "{"] => {
+                    let times = parse_at(Day02::DAY, line, times).map_err(|e| e.on_line(n + 1))?;
+                    open.push((Block::Repeat(times), n + 1));
+                    steps.push(Vec::new());
+                    continue;
+                }
+                ["repeat", ..] => {
+                    return Err(error(words[0], "expected `repeat <times> {`".to_string()))
+                }
+                ["macro", name, "{"] => {
+                    if !open.is_empty() {
+                        return Err(error(
+                            words[0],
+                            "macros are defined outside of blocks".to_string(),
+                        ));
+                    }
+                    if !is_name(name) {
+                        return Err(error(name, format!("invalid macro name `{}`", name)));
+                    }
+                    if names.contains_key(name) {
+                        return Err(error(name, format!("macro `{}` is already defined", name)));
+                    }
+                    open.push((Block::Macro(name), n + 1));
+                    steps.push(Vec::new());
+                    continue;
+                }
+                ["macro", ..] => {
+                    return Err(error(words[0], "expected `macro <name> {`".to_string()))
+                }
+                [direction @ ("forward" | "up" | "down"), length] => {
+                    let direction = match *direction {
+                        "forward" => Direction::Forward,
+                        "up" => Direction::Up,
+                        _ => Direction::Down,
+                    };
+                    let at = error(length, String::new());
+                    let length = match length.starts_with(['+', '-']) {
+                        true => parse_at(Day02::DAY, line, length).map(Length::By),
+                        false => parse_at(Day02::DAY, line, length).map(Length::Exact),
+                    };
+                    Step::Move {
+                        direction,
+                        length: length.map_err(|e| e.on_line(n + 1))?,
+                        at: (at.line, at.column),
+                    }
+                }
+                [name] => match names.get(name) {
+                    Some(&i) => Step::Call(i),
+                    None => {
+                        return Err(error(name, format!("unknown command or macro `{}`", name)))
+                    }
+                },
+                _ => {
+                    return Err(error(
+                        words[0],
+                        "expected `<direction> <length>`, a block or a macro".to_string(),
+                    ))
+                }
+            };
+            steps
+                .last_mut()
+                .expect("the top level is never closed")
+                .push(step);
+        }
+        if let Some((block, line)) = open.pop() {
+            let what = match block {
+                Block::Repeat(_) => "`repeat`".to_string(),
+                Block::Macro(name) => format!("macro `{}`", name),
+            };
+            return Err(ParseError::new(
+                Day02::DAY,
+                s.lines().count() + 1,
+                1,
+                format!("{} from line {} is never closed", what, line),
+            ));
+        }
+        Ok(Self {
+            steps: steps.pop().expect("the top level"),
+            macros,
+            lengths,
+        })
+    }
+}
+
+/// Whether `name` can name a macro, which the commands and keywords can't.
+fn is_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !["forward", "up", "down", "repeat", "macro"].contains(&name)
+}
+
+/// How many commands `steps` expand to, up to `u64::MAX`, given the lengths
+/// of the macros they call.
+fn count(steps: &[Step], lengths: &[u64]) -> u64 {
+    steps
+        .iter()
+        .map(|step| match step {
+            Step::Move { .. } => 1,
+            Step::Repeat { times, len, .. } => len.saturating_mul(*times as u64),
+            Step::Call(i) => lengths[*i],
+        })
+        .fold(0, u64::saturating_add)
+}
+
+/// The commands written so far and the last length of every direction.
+struct Expansion {
+    commands: Vec<Command>,
+    last: [usize; 3],
+}
+
+impl Script {
+    /// How many commands the script expands to, up to `u64::MAX`.
+    pub fn len(&self) -> u64 {
+        count(&self.steps, &self.lengths)
+    }
+
+    /// Whether the script expands to no commands at all.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The course the script stands for, with every block and macro
+    /// expanded and every relative length resolved. Scripts of more than
+    /// [`MAX_COMMANDS`] commands are refused before expanding anything.
+    pub fn commands(&self) -> Result<Vec<Command>, ParseError> {
+        let len = self.len();
+        if len > MAX_COMMANDS as u64 {
+            return Err(ParseError::new(
+                Day02::DAY,
+                1,
+                1,
+                format!(
+                    "the script expands to {} commands, more than the {} allowed",
+                    len, MAX_COMMANDS
+                ),
+            ));
+        }
+        let mut expansion = Expansion {
+            commands: Vec::with_capacity(len as usize),
+            last: [0; 3],
+        };
+        self.expand(&self.steps, &mut expansion)?;
+        Ok(expansion.commands)
+    }
+
+    fn expand(&self, steps: &[Step], expansion: &mut Expansion) -> Result<(), ParseError> {
+        for step in steps {
+            match step {
+                Step::Move {
+                    direction,
+                    length,
+                    at,
+                } => {
+                    let last = &mut expansion.last[*direction as usize];
+                    *last = match *length {
+                        Length::Exact(len) => len,
+                        Length::By(by) => {
+                            last.checked_add_signed(by as isize).ok_or_else(|| {
+                                ParseError::new(
+                                    Day02::DAY,
+                                    at.0,
+                                    at.1,
+                                    format!("the length drops below 0 from {}", last),
+                                )
+                            })?
+                        }
+                    };
+                    expansion.commands.push(direction.command(*last));
+                }
+                Step::Repeat { times, body, len } if *len > 0 => {
+                    for _ in 0..*times {
+                        self.expand(body, expansion)?;
+                    }
+                }
+                Step::Repeat { .. } => {}
+                Step::Call(i) => self.expand(&self.macros[*i], expansion)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_text, part1, part2};
+
+    const EXAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+    #[test]
+    fn test_plain_course() {
+        let commands = parse_text(EXAMPLE).unwrap();
+        assert_eq!((part1(&commands), part2(&commands)), (Ok(150), Ok(900)));
+    }
+
+    #[test]
+    fn test_blocks_and_macros() {
+        let script = "\
+# the example, written by a planner
+macro turn {
+    down 5   # dive
+    forward +3
+}
+forward 5
+turn
+up 3
+repeat 2 {
+    repeat 1 {
+        down 4
+    }
+}
+forward -6
+";
+        let commands = parse_text(script).unwrap();
+        let expanded = "forward 5\ndown 5\nforward 8\nup 3\ndown 4\ndown 4\nforward 2\n";
+        assert_eq!(commands, parse_text(expanded).unwrap());
+        assert_eq!((part1(&commands), part2(&commands)), (Ok(150), Ok(900)));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |script: &str| parse_text(script).unwrap_err().to_string();
+        assert_eq!(
+            error("forward 1\n  sideways 2\n"),
+            "day 2, line 2, column 3: expected `<direction> <length>`, a block or a macro"
+        );
+        assert_eq!(
+            error("dive\n"),
+            "day 2, line 1, column 1: unknown command or macro `dive`"
+        );
+        assert_eq!(
+            error("down 1\nup -2\n"),
+            "day 2, line 2, column 4: the length drops below 0 from 0"
+        );
+        assert_eq!(
+            error("repeat 2 {\nforward 1\n"),
+            "day 2, line 3, column 1: `repeat` from line 1 is never closed"
+        );
+        assert_eq!(error("}\n"), "day 2, line 1, column 1: `}` without a block");
+        assert_eq!(
+            error("repeat 100000000 {\nrepeat 100000000 {\nforward 1\n}\n}\n"),
+            "day 2, line 1, column 1: the script expands to 10000000000000000 commands, \
+             more than the 10000000 allowed"
+        );
+        let nested = (1..40)
+            .map(|i| format!("macro m{} {{\nm{}\nm{}\n}}\n", i, i - 1, i - 1))
+            .collect::<String>();
+        let script = format!("macro m0 {{\nforward 1\n}}\n{}m39\n", nested);
+        assert_eq!(script.parse::<Script>().unwrap().len(), 1 << 39);
+        assert!(parse_text(&script).is_err());
+        let empty = "repeat 100000000000 {\n}\n".parse::<Script>().unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.commands().unwrap(), []);
+    }
+}
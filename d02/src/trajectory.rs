@@ -0,0 +1,160 @@
+//! The state after every command of a run, to plot dive profiles.
+
+use std::{error::Error, io::Write};
+
+use crate::{
+    model::{Gauges, RunError, SubmarineModel},
+    Command,
+};
+
+/// The state after one command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    /// How many commands ran, 1 after the first.
+    pub step: usize,
+    /// The command that just ran.
+    pub command: Command,
+    /// How far forward the submarine went.
+    pub horizontal: usize,
+    /// How far below the surface it is.
+    pub depth: usize,
+    /// Where it is heading, for models with an aim.
+    pub aim: Option<usize>,
+}
+
+/// Runs a model one command at a time, giving a [`Sample`] after each,
+/// up to the first command the model can't carry out.
+pub struct Trajectory<'a, M: SubmarineModel, I> {
+    model: &'a M,
+    /// `None` once a command failed.
+    state: Option<M::State>,
+    commands: I,
+    step: usize,
+}
+
+impl<M, I> Iterator for Trajectory<'_, M, I>
+where
+    M: SubmarineModel,
+    M::State: Gauges,
+    I: Iterator<Item = Command>,
+{
+    type Item = Result<Sample, RunError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.as_ref()?;
+        let command = self.commands.next()?;
+        let state = self.state.take()?;
+        self.step += 1;
+        let state = match self.model.step(state, command) {
+            Ok(state) => state,
+            Err(fault) => {
+                return Some(Err(RunError {
+                    step: self.step,
+                    command,
+                    fault,
+                }))
+            }
+        };
+        let position = state.position();
+        let sample = Sample {
+            step: self.step,
+            command,
+            horizontal: position.horizontal,
+            depth: position.depth,
+            aim: state.aim(),
+        };
+        self.state = Some(state);
+        Some(Ok(sample))
+    }
+}
+
+/// The trajectory of `model` through `commands`.
+pub fn trajectory<M, I>(model: &M, commands: I) -> Trajectory<'_, M, I::IntoIter>
+where
+    M: SubmarineModel,
+    I: IntoIterator<Item = Command>,
+{
+    Trajectory {
+        model,
+        state: Some(model.initial()),
+        commands: commands.into_iter(),
+        step: 0,
+    }
+}
+
+/// Writes `samples` as CSV with a header, leaving the aim empty for models
+/// without one. A failed command ends the CSV and is returned once the rows
+/// before it are written.
+pub fn write_csv<W, I>(mut out: W, samples: I) -> Result<(), Box<dyn Error>>
+where
+    W: Write,
+    I: IntoIterator<Item = Result<Sample, RunError>>,
+{
+    writeln!(out, "step,command,horizontal,depth,aim")?;
+    for sample in samples {
+        let s = match sample {
+            Ok(s) => s,
+            Err(e) => {
+                out.flush()?;
+                return Err(e.into());
+            }
+        };
+        let aim = s.aim.map(|a| a.to_string()).unwrap_or_default();
+        writeln!(
+            out,
+            "{},{},{},{},{}",
+            s.step, s.command, s.horizontal, s.depth, aim
+        )?;
+    }
+    Ok(out.flush()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{AimModel, DepthModel, Fault, Gauge},
+        parse_text,
+    };
+
+    const EXAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+    #[test]
+    fn test_trajectory() {
+        let commands = parse_text(EXAMPLE).unwrap();
+        let samples = trajectory(&AimModel::default(), commands.iter().copied())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(samples.len(), 6);
+        let third = Sample {
+            step: 3,
+            command: Command::Forward(8),
+            horizontal: 13,
+            depth: 40,
+            aim: Some(5),
+        };
+        assert_eq!(samples[2], third);
+
+        let mut csv = Vec::new();
+        write_csv(
+            &mut csv,
+            trajectory(&DepthModel::default(), commands).take(2),
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "step,command,horizontal,depth,aim\n1,forward 5,5,0,\n2,down 5,5,5,\n"
+        );
+    }
+
+    #[test]
+    fn test_surfacing() {
+        let commands = parse_text("down 1\nup 2\nforward 3\n").unwrap();
+        let model = DepthModel::default();
+        let mut samples = trajectory(&model, commands);
+        assert_eq!(samples.next().map(|s| s.unwrap().depth), Some(1));
+        let e = samples.next().unwrap().unwrap_err();
+        assert_eq!((e.step, e.fault), (2, Fault::Surfaced(Gauge::Depth)));
+        assert!(samples.next().is_none());
+    }
+}
@@ -0,0 +1,14 @@
+//! Random diagnostic reports.
+
+use rand::{seq::SliceRandom, Rng};
+
+/// `size` distinct 12 bit numbers, at most 4096 of them.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut numbers: Vec<usize> = (0..1 << 12).collect();
+    numbers.shuffle(rng);
+    numbers
+        .iter()
+        .take(size.max(1))
+        .map(|n| format!("{:012b}\n", n))
+        .collect()
+}
@@ -0,0 +1,227 @@
+//! Day 3: Binary Diagnostic, reading power and life support ratings out of
+//! binary numbers.
+#![warn(missing_docs)]
+
+use std::{cmp::Ordering, error::Error, ops::Shl, path::Path};
+
+use aoc_common::{parse_lines_with, read_input, ParseError, Solution};
+
+pub mod generate;
+
+/// The numbers of a diagnostic report, all with the same number of bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// The numbers, in the order of the report.
+    pub numbers: Vec<usize>,
+    /// How many bits each number has, leading zeros included.
+    pub width: usize,
+}
+
+/// Reads and parses the diagnostic report at `path`.
+pub fn read_lines_as_numbers<P: AsRef<Path>>(path: P) -> Result<Report, Box<dyn Error>> {
+    Ok(parse_input(&read_input(path)?)?)
+}
+
+/// One binary number per line, at least one of them, each with as many bits
+/// as the first.
+pub fn parse_input(input: &str) -> Result<Report, ParseError> {
+    let mut width = None;
+    let numbers = parse_lines_with(input, |l| {
+        let bits = l.trim();
+        if let Some(i) = bits.find(|c| c != '0' && c != '1') {
+            return Err(ParseError::at(
+                Day03::DAY,
+                l,
+                &bits[i..],
+                "expected only `0` and `1`",
+            ));
+        }
+        let max = usize::BITS as usize;
+        match *width.get_or_insert(bits.len()) {
+            w if w > max => Err(ParseError::at(
+                Day03::DAY,
+                l,
+                &bits[max..],
+                format!("expected at most {} bits", max),
+            )),
+            w if bits.len() != w => Err(ParseError::at(
+                Day03::DAY,
+                l,
+                &bits[w.min(bits.len())..],
+                format!("expected {} bits, like the first number", w),
+            )),
+            _ => Ok(usize::from_str_radix(bits, 2).expect("only binary digits")),
+        }
+    })?;
+    match width {
+        Some(width) => Ok(Report { numbers, width }),
+        None => Err(ParseError::at(
+            Day03::DAY,
+            input,
+            &input[input.len()..],
+            "expected at least one binary number",
+        )),
+    }
+}
+
+/// Bit `p` of `n`, counting from the most significant of `width` bits.
+fn digit(n: usize, p: usize, width: usize) -> usize {
+    (n >> (width - 1 - p)) & 1
+}
+
+fn gamma(report: &Report) -> usize {
+    let mut ones = vec![0usize; report.width];
+    let common = report.numbers.len() / 2;
+    report
+        .numbers
+        .iter()
+        .cloned()
+        .for_each(|n| (0..report.width).for_each(|p| ones[p] += digit(n, p, report.width)));
+    ones.into_iter()
+        .map(|n| match n < common {
+            true => 0usize,
+            false => 1,
+        })
+        .fold(0usize, |ans, n| ans.shl(1) + n)
+}
+
+fn epsilon(n: usize, width: usize) -> usize {
+    let op = usize::MAX >> (usize::BITS as usize - width);
+    n ^ op
+}
+
+#[derive(Debug, Clone, Copy)]
+#[repr(usize)]
+enum Lsr {
+    CO2,
+    O2,
+}
+
+fn life_support_rating(report: &Report, a: Lsr) -> usize {
+    let width = report.width;
+    let mut input = report.numbers.clone();
+    for p in 0..width {
+        let find = match (input
+            .iter()
+            .cloned()
+            .filter(|n| digit(*n, p, width) == (a as usize))
+            .count()
+            * 2)
+        .cmp(&input.len())
+        {
+            Ordering::Less => 0,
+            Ordering::Equal => a as usize,
+            Ordering::Greater => 1,
+        };
+        // every number left has the same bit here, so there is nothing to keep
+        if input.iter().all(|n| digit(*n, p, width) != find) {
+            continue;
+        }
+        input.retain(|n| digit(*n, p, width) == find);
+        match input.len() {
+            1 => return input[0],
+            0 => unreachable!(),
+            _ => continue,
+        }
+    }
+    // only copies of the same number are left
+    input[0]
+}
+
+/// The power consumption, the gamma rate times the epsilon rate.
+pub fn part1(input: &Report) -> usize {
+    let gam = gamma(input);
+    let eps = epsilon(gam, input.width);
+    gam * eps
+}
+
+/// The life support rating, the oxygen generator rating times the CO2
+/// scrubber rating.
+pub fn part2(input: &Report) -> usize {
+    let oxy = life_support_rating(input, Lsr::O2);
+    let c02 = life_support_rating(input, Lsr::CO2);
+    oxy * c02
+}
+
+/// The [`Solution`] of day 3.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Report;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_logic() {
+        let d = 0b_101010101010;
+        let mut expected = 1;
+        for i in 0..12 {
+            assert_eq!(digit(d, i, 12), expected, "\nround: {}", i);
+            match expected {
+                0 | 1 => expected ^= 1,
+                n => unreachable!("should alway be 0 or 1, found: {}", n),
+            }
+        }
+    }
+
+    #[test]
+    fn test_eps() {
+        let d = 0b_101010101010;
+        let o = 0b_010101010101;
+        assert_eq!(d, epsilon(o, 12));
+        assert_eq!(o, epsilon(d, 12));
+        assert_eq!(0b_010, epsilon(0b_101, 3));
+        assert_eq!(usize::MAX, epsilon(0, usize::BITS as usize));
+    }
+    #[test]
+    fn test_shared_prefix() {
+        let input = parse_input("000000000001\n000000000000\n").unwrap();
+        assert_eq!(life_support_rating(&input, Lsr::O2), 1);
+        assert_eq!(life_support_rating(&input, Lsr::CO2), 0);
+        let input = parse_input("101\n101\n").unwrap();
+        assert_eq!(life_support_rating(&input, Lsr::O2), 0b_101);
+        assert_eq!(parse_input("\n").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_example() {
+        let input = "00100\n11110\n10110\n10111\n10101\n01111\n\
+                     00111\n11100\n10000\n11001\n00010\n01010\n";
+        let report = parse_input(input).unwrap();
+        assert_eq!(report.width, 5);
+        assert_eq!((part1(&report), part2(&report)), (198, 230));
+    }
+
+    #[test]
+    fn test_widths() {
+        let e = parse_input("0101\n101\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+        assert_eq!(e.description, "expected 4 bits, like the first number");
+        let e = parse_input("0101\n10101\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 5));
+        let e = parse_input(&format!("{}\n", "1".repeat(65))).unwrap_err();
+        assert_eq!(
+            (e.column, e.description.as_str()),
+            (65, "expected at most 64 bits")
+        );
+    }
+}
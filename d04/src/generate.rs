@@ -0,0 +1,34 @@
+//! Random bingo games.
+
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{parse_text, part2};
+
+/// A draw order of every number from 0 to 99 and `size` boards, so every
+/// board wins at some point, and only one of them last.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    loop {
+        let input = game(rng, size);
+        let (numbers, boards) = parse_text(&input).expect("generated games parse");
+        if part2(&numbers, &boards).is_ok() {
+            return input;
+        }
+    }
+}
+
+fn game<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut numbers: Vec<usize> = (0..100).collect();
+    numbers.shuffle(rng);
+    let mut s = numbers.iter().join(",");
+    for _ in 0..size.max(1) {
+        numbers.shuffle(rng);
+        s.push('\n');
+        for row in numbers[..25].chunks(5) {
+            s.push('\n');
+            s.push_str(&row.iter().map(|n| format!("{:2}", n)).join(" "));
+        }
+    }
+    s.push('\n');
+    s
+}
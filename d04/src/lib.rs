@@ -0,0 +1,291 @@
+//! Day 4: Giant Squid, playing bingo against the squid.
+#![warn(missing_docs)]
+
+use std::{
+    cmp::{max, min},
+    collections::HashSet,
+    error::Error,
+    fmt::{self, Display},
+    path::Path,
+};
+
+use aoc_common::{parse_at, parse_comma_list, read_input, ParseError, Solution};
+use itertools::Itertools;
+
+pub mod generate;
+
+/// A 5 by 5 bingo board.
+#[derive(Debug, Clone)]
+pub struct Bingo {
+    board: Vec<usize>,
+}
+
+impl Bingo {
+    /// A board from its 25 numbers, row by row.
+    ///
+    /// # Panics
+    ///
+    /// When `v` doesn't hold 25 numbers.
+    pub fn new(v: Vec<usize>) -> Self {
+        assert_eq!(v.len(), 25, "a bingo board has 25 numbers");
+        Self { board: v }
+    }
+
+    /// The score once a row or column is marked, `last` being the number
+    /// drawn last, else the most marked numbers of any row or column.
+    pub fn bingo(&self, drawn: &HashSet<usize>, last: usize) -> Result<usize, usize> {
+        let horizontal = self
+            .board
+            .chunks(5)
+            .map(|n| n.iter().filter(|n| drawn.contains(n)).count())
+            .enumerate()
+            .max_by_key(|(_, k)| *k)
+            .unwrap();
+        let vertical = (0..5)
+            .map(|n| {
+                self.board
+                    .iter()
+                    .skip(n)
+                    .step_by(5)
+                    .filter(|d| drawn.contains(d))
+                    .count()
+            })
+            .enumerate()
+            .max_by_key(|(_, k)| *k)
+            .unwrap();
+        match max(vertical.1, horizontal.1) {
+            5 => Ok(self
+                .board
+                .iter()
+                .copied()
+                .filter(|d| !drawn.contains(d))
+                .sum::<usize>()
+                * last),
+            n if (0..5).contains(&n) => Err(n),
+            _ => unreachable!("boards with odd size"),
+        }
+    }
+}
+
+/// Why a game has no answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BingoError {
+    /// The draws ran out with this many boards that never won.
+    DrawsRunOut(usize),
+    /// This many boards won last, on the same draw.
+    TiedLast(usize),
+}
+
+impl Display for BingoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BingoError::DrawsRunOut(n) => {
+                write!(f, "the draws run out with {} boards that never win", n)
+            }
+            BingoError::TiedLast(n) => write!(f, "the last {} boards win on the same draw", n),
+        }
+    }
+}
+
+impl Error for BingoError {}
+
+/// Reads and parses the draw order and boards at `path`.
+pub fn read_bingoes<P: AsRef<Path>>(path: P) -> Result<(Vec<usize>, Vec<Bingo>), Box<dyn Error>> {
+    Ok(parse_text(&read_input(path)?)?)
+}
+
+/// The draw order on the first line, then the boards.
+pub fn parse_text(input: &str) -> Result<(Vec<usize>, Vec<Bingo>), ParseError> {
+    let mut lines = input.lines();
+    let numbers = parse_comma_list(Day04::DAY, lines.next().unwrap_or_default())?;
+    let rows = lines
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let row = l
+                .split_ascii_whitespace()
+                .map(|v| parse_at(Day04::DAY, input, v))
+                .collect::<Result<Vec<usize>, _>>()?;
+            match row.len() {
+                5 => Ok(row),
+                n => Err(ParseError::at(
+                    Day04::DAY,
+                    input,
+                    l,
+                    format!("expected 5 numbers in a board row, found {}", n),
+                )),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if rows.is_empty() {
+        return Err(ParseError::at(
+            Day04::DAY,
+            input,
+            &input[input.len()..],
+            "expected at least one board after the draw order",
+        ));
+    }
+    if rows.len() % 5 != 0 {
+        return Err(ParseError::at(
+            Day04::DAY,
+            input,
+            &input[input.len()..],
+            "expected 5 rows in the last board",
+        ));
+    }
+    let bingoes = rows.chunks(5).map(|c| Bingo::new(c.concat())).collect();
+    Ok((numbers, bingoes))
+}
+
+/// The score of the first board to win.
+#[allow(clippy::manual_try_fold)]
+pub fn part1(numbers: &[usize], boards: &[Bingo]) -> Result<usize, BingoError> {
+    let mut n_to_draw = 5;
+    let mut drawn = HashSet::<usize>::new();
+    let mut to_draw = numbers.iter().copied();
+    let mut last = 0;
+    loop {
+        let left = to_draw.len();
+        drawn.extend(
+            std::iter::from_fn(|| {
+                last = to_draw.next()?;
+                Some(last)
+            })
+            .take(n_to_draw),
+        );
+        let res = boards
+            .iter()
+            .map(|b| b.bingo(&drawn, last))
+            .fold(Err(0), |p, v| match p {
+                Ok(p) => Ok(p),
+                Err(n) => match v {
+                    Ok(n) => Ok(n),
+                    Err(v) => Err(max(n, v)),
+                },
+            });
+        match res {
+            Ok(n) => break Ok(n),
+            Err(_) if to_draw.len() == left => break Err(BingoError::DrawsRunOut(boards.len())),
+            Err(n) => n_to_draw = 5 - n,
+        }
+    }
+}
+
+/// The score of the last board to win, when only one board wins last.
+pub fn part2(numbers: &[usize], boards: &[Bingo]) -> Result<usize, BingoError> {
+    let mut n_to_draw = 5;
+    let mut drawn = HashSet::<usize>::new();
+    let mut to_draw = numbers.iter().copied();
+    let mut last = 0;
+    let mut boards = boards.to_vec();
+    loop {
+        let left = to_draw.len();
+        drawn.extend(
+            std::iter::from_fn(|| {
+                last = to_draw.next()?;
+                Some(last)
+            })
+            .take(n_to_draw),
+        );
+        let res = boards.iter().map(|b| b.bingo(&drawn, last)).collect_vec();
+        if res.iter().all(Result::is_err) && to_draw.len() == left {
+            break Err(BingoError::DrawsRunOut(boards.len()));
+        }
+        match boards.len() {
+            1 => match res[0] {
+                Ok(e) => break Ok(e),
+                Err(n) => n_to_draw = 5 - n,
+            },
+            n if res.iter().all(Result::is_ok) => break Err(BingoError::TiedLast(n)),
+            _ => {
+                boards = boards
+                    .into_iter()
+                    .zip(res)
+                    .filter(|(_, r)| match r {
+                        Ok(_) => false,
+                        Err(e) => {
+                            n_to_draw = min(n_to_draw, 5 - e);
+                            true
+                        }
+                    })
+                    .map(|(v, _)| v)
+                    .collect()
+            }
+        }
+    }
+}
+
+/// The [`Solution`] of day 4.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = (Vec<usize>, Vec<Bingo>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_text(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(&input.0, &input.1)?)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(&input.0, &input.1)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+
+    #[test]
+    fn test_example() {
+        let (numbers, boards) = parse_text(EXAMPLE).unwrap();
+        assert_eq!(part1(&numbers, &boards), Ok(4512));
+        assert_eq!(part2(&numbers, &boards), Ok(1924));
+    }
+
+    #[test]
+    fn test_no_answer() {
+        let (numbers, boards) = parse_text(EXAMPLE).unwrap();
+        assert_eq!(
+            part1(&numbers[..4], &boards),
+            Err(BingoError::DrawsRunOut(3))
+        );
+        assert_eq!(
+            part2(&numbers[..12], &boards),
+            Err(BingoError::DrawsRunOut(2))
+        );
+        let twice = [boards[2].clone(), boards[2].clone()];
+        assert_eq!(part2(&numbers, &twice), Err(BingoError::TiedLast(2)));
+        assert_eq!(
+            BingoError::TiedLast(2).to_string(),
+            "the last 2 boards win on the same draw"
+        );
+    }
+}
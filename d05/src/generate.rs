@@ -0,0 +1,23 @@
+//! Random hydrothermal vent lines.
+
+use rand::{Rng, RngExt};
+
+/// `size` horizontal, vertical or diagonal lines on a 1000 by 1000 floor.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let (x1, y1): (i32, i32) = (rng.random_range(0..1000), rng.random_range(0..1000));
+            let len = rng.random_range(1..200);
+            let (dx, dy) = match rng.random_range(0..3) {
+                0 => (1, 0),
+                1 => (0, 1),
+                _ => (1, if rng.random_bool(0.5) { 1 } else { -1 }),
+            };
+            let (x2, y2) = (x1 + dx * len, y1 + dy * len);
+            match (0..1000).contains(&x2) && (0..1000).contains(&y2) {
+                true => format!("{},{} -> {},{}\n", x1, y1, x2, y2),
+                false => format!("{},{} -> {},{}\n", x1, y1, x1, y1),
+            }
+        })
+        .collect()
+}
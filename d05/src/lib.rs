@@ -0,0 +1,199 @@
+//! Day 5: Hydrothermal Venture, finding where lines of vents overlap.
+#![warn(missing_docs)]
+
+use std::{cmp::{Ordering, max}, collections::HashMap, error::Error, path::Path};
+
+use aoc_common::{parse_at, parse_lines_with, read_input, Grid, Image, ParseError, Picture, Solution};
+
+pub mod generate;
+
+/// A point on the ocean floor.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point {
+    x: usize,
+    y: usize,
+}
+
+impl Point {
+    /// The point `x` steps right of and `y` steps below the origin.
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// The column.
+    pub fn x(&self) -> usize {
+        self.x
+    }
+
+    /// The row.
+    pub fn y(&self) -> usize {
+        self.y
+    }
+
+    fn parse(line: &str, s: &str) -> Result<Self, ParseError> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(Day05::DAY, line, s, "expected `x,y`"))?;
+        Ok(Self {
+            x: parse_at(Day05::DAY, line, x.trim())?,
+            y: parse_at(Day05::DAY, line, y.trim())?,
+        })
+    }
+
+    /// Every point of a horizontal or vertical line, `None` for diagonals.
+    pub fn straight_range(&self, p2: Point) -> Option<Vec<Point>> {
+        if self.x == p2.x {
+            let (min, max) = match self.y < p2.y {
+                true => (self.y, p2.y),
+                false => (p2.y, self.y),
+            };
+            Some((min..=max).map(|n| Point {x: self.x, y: n}).collect())
+        } else if self.y == p2.y {
+            let (min, max) = match self.x < p2.x {
+                true => (self.x, p2.x),
+                false => (p2.x, self.x),
+            };
+            Some((min..=max).map(|n| Point {x: n, y: self.y}).collect())
+        } else {
+            None
+        }
+    }
+
+    fn delta(n1: usize, n2: usize) -> isize {
+        match ((n2 as isize) - (n1 as isize)).cmp(&0) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }
+    }
+
+    /// Every point of a horizontal, vertical or 45 degree diagonal line.
+    pub fn range(&self, p2: Point) -> Vec<Point> {
+        let dx = Self::delta(self.x, p2.x);
+        let dy = Self::delta(self.y, p2.y);
+        (0isize..=((max(self.x.abs_diff(p2.x),self.y.abs_diff(p2.y))) as isize))
+            .map(|n| Point {
+                x: ((self.x as isize) + dx * n) as usize,
+                y: ((self.y as isize) + dy * n) as usize,
+            })
+            .collect()
+    }
+}
+
+/// Reads and parses the vent lines at `path`.
+pub fn read_file<P: AsRef<Path>>(path: P) -> Result<Vec<(Point, Point)>, Box<dyn Error>> {
+    Ok(parse_lines(&read_input(path)?)?)
+}
+
+/// One `x1,y1 -> x2,y2` line per line.
+pub fn parse_lines(file: &str) -> Result<Vec<(Point, Point)>, ParseError> {
+    parse_lines_with(file, |line| {
+        let (p1, p2) = line
+            .split_once("->")
+            .ok_or_else(|| ParseError::at(Day05::DAY, line, line.trim(), "expected `x1,y1 -> x2,y2`"))?;
+        Ok((Point::parse(line, p1.trim())?, Point::parse(line, p2.trim())?))
+    })
+}
+
+/// How many lines cover each point, leaving out the diagonals unless
+/// `diagonals` is set.
+pub fn overlaps(lines: &[(Point, Point)], diagonals: bool) -> HashMap<Point, usize> {
+    let mut map: HashMap<Point, usize> = HashMap::new();
+    lines
+        .iter()
+        .filter_map(|(p1, p2)| match diagonals {
+            true => Some(p1.range(*p2)),
+            false => p1.straight_range(*p2),
+        })
+        .flatten()
+        .for_each(|p| *map.entry(p).or_default() += 1);
+    map
+}
+
+/// How many points at least two horizontal or vertical lines cover.
+pub fn part1(lines: &[(Point, Point)]) -> usize {
+    overlaps(lines, false).values().filter(|&v| *v >= 2).count()
+}
+
+/// How many points at least two lines cover, diagonals included.
+pub fn part2(lines: &[(Point, Point)]) -> usize {
+    overlaps(lines, true).values().filter(|&v| *v >= 2).count()
+}
+
+/// The overlaps as shades of grey, white where the most lines cross.
+pub fn heatmap(overlaps: &HashMap<Point, usize>) -> Image {
+    let width = overlaps.keys().map(|p| p.x + 1).max().unwrap_or(0);
+    let height = overlaps.keys().map(|p| p.y + 1).max().unwrap_or(0);
+    let mut grid = Grid::filled(width, height, 0);
+    for (p, &n) in overlaps {
+        grid[(p.x, p.y)] = n;
+    }
+    Image::heat(&grid)
+}
+
+/// The [`Solution`] of day 5.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Vec<(Point, Point)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input))
+    }
+}
+
+impl Picture for Day05 {
+    fn image(input: &Self::Input<'_>) -> Result<Image, Box<dyn Error>> {
+        Ok(heatmap(&overlaps(input, true)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    #[test]
+    fn test_part1() {
+        let lines = parse_lines(EXAMPLE).unwrap();
+        assert_eq!(part1(&lines), 5)
+    }
+
+    #[test]
+    fn test_part2() {
+        let lines = parse_lines(EXAMPLE).unwrap();
+        assert_eq!(part2(&lines), 12)
+    }
+
+    #[test]
+    fn test_heatmap() {
+        let lines = parse_lines(EXAMPLE).unwrap();
+        let Image::Grey(grid) = heatmap(&overlaps(&lines, false)) else {
+            panic!("the heatmap is grey");
+        };
+        assert_eq!((grid.width(), grid.height()), (10, 10));
+        assert_eq!(grid.row(9), [255, 255, 255, 127, 127, 127, 0, 0, 0, 0]);
+    }
+}
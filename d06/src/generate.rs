@@ -0,0 +1,11 @@
+//! Random lanternfish schools.
+
+use rand::{Rng, RngExt};
+
+/// `size` fish with timers from 1 to 5, like the puzzle inputs.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let fish: Vec<_> = (0..size.max(1))
+        .map(|_| rng.random_range(1..=5).to_string())
+        .collect();
+    format!("{}\n", fish.join(","))
+}
@@ -0,0 +1,204 @@
+//! Day 6: Lanternfish, counting an exponentially growing school of fish.
+#![warn(missing_docs)]
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use aoc_common::{parse_at, Explore, ParseError, Session, Solution};
+
+pub mod generate;
+mod repl;
+
+/// How many lanternfish there are at each timer value.
+///
+/// Iterating gives the counts of each day, starting with the initial school,
+/// and ends before there are more fish than a `usize` can count.
+#[derive(Debug, Clone)]
+pub struct Ecosystem {
+    fish: [usize; 9],
+    start: bool,
+}
+
+impl Ecosystem {
+    /// A school from the timer of each fish.
+    ///
+    /// # Panics
+    ///
+    /// When a timer is above 8.
+    pub fn new(fish: &[usize]) -> Self {
+        let mut map = [0; 9];
+        for f in fish {
+            map[*f] += 1;
+        }
+        Self {
+            fish: map,
+            start: true,
+        }
+    }
+}
+
+impl Iterator for Ecosystem {
+    type Item = [usize; 9];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.start {
+            true => {
+                self.start = false;
+            }
+            false => {
+                let mut fish = self.fish;
+                fish.rotate_left(1);
+                fish[6] = fish[6].checked_add(fish[8])?;
+                fish.iter()
+                    .try_fold(0usize, |total, &n| total.checked_add(n))?;
+                self.fish = fish;
+            }
+        };
+        Some(self.fish)
+    }
+}
+
+impl FromStr for Ecosystem {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fish = s
+            .trim()
+            .split(',')
+            .map(|t| match parse_at(Day06::DAY, s, t.trim())? {
+                n @ 0..=8 => Ok(n),
+                n => Err(ParseError::at(
+                    Day06::DAY,
+                    s,
+                    t.trim(),
+                    format!("a timer is at most 8, found {}", n),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(&fish))
+    }
+}
+
+/// There are more fish than a `usize` can count after this many days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyFish(pub usize);
+
+impl Display for TooManyFish {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "after {} days there are too many fish to count", self.0)
+    }
+}
+
+impl Error for TooManyFish {}
+
+/// How many fish there are after `days` days.
+pub fn count(fish: &Ecosystem, days: usize) -> Result<usize, TooManyFish> {
+    fish.clone()
+        .nth(days)
+        .map(|fish| fish.iter().sum())
+        .ok_or(TooManyFish(days))
+}
+
+/// How many fish there are after 80 days.
+pub fn part1(fish: &Ecosystem) -> Result<usize, TooManyFish> {
+    count(fish, 80)
+}
+
+/// How many fish there are after 256 days.
+pub fn part2(fish: &Ecosystem) -> Result<usize, TooManyFish> {
+    count(fish, 256)
+}
+
+/// The [`Solution`] of day 6.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Ecosystem;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input)?)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input)?)
+    }
+}
+
+impl Explore for Day06 {
+    fn session(input: Self::Input<'_>) -> Box<dyn Session + '_> {
+        Box::new(repl::School::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST: &[&[usize]] = &[
+        &[3, 4, 3, 1, 2],
+        &[2, 3, 2, 0, 1],
+        &[1, 2, 1, 6, 0, 8],
+        &[0, 1, 0, 5, 6, 7, 8],
+        &[6, 0, 6, 4, 5, 6, 7, 8, 8],
+        &[5, 6, 5, 3, 4, 5, 6, 7, 7, 8],
+        &[4, 5, 4, 2, 3, 4, 5, 6, 6, 7],
+        &[3, 4, 3, 1, 2, 3, 4, 5, 5, 6],
+        &[2, 3, 2, 0, 1, 2, 3, 4, 4, 5],
+        &[1, 2, 1, 6, 0, 1, 2, 3, 3, 4, 8],
+        &[0, 1, 0, 5, 6, 0, 1, 2, 2, 3, 7, 8],
+        &[6, 0, 6, 4, 5, 6, 0, 1, 1, 2, 6, 7, 8, 8, 8],
+        &[5, 6, 5, 3, 4, 5, 6, 0, 0, 1, 5, 6, 7, 7, 7, 8, 8],
+        &[4, 5, 4, 2, 3, 4, 5, 6, 6, 0, 4, 5, 6, 6, 6, 7, 7, 8, 8],
+        &[3, 4, 3, 1, 2, 3, 4, 5, 5, 6, 3, 4, 5, 5, 5, 6, 6, 7, 7, 8],
+        &[2, 3, 2, 0, 1, 2, 3, 4, 4, 5, 2, 3, 4, 4, 4, 5, 5, 6, 6, 7],
+        &[
+            1, 2, 1, 6, 0, 1, 2, 3, 3, 4, 1, 2, 3, 3, 3, 4, 4, 5, 5, 6, 8,
+        ],
+        &[
+            0, 1, 0, 5, 6, 0, 1, 2, 2, 3, 0, 1, 2, 2, 2, 3, 3, 4, 4, 5, 7, 8,
+        ],
+        &[
+            6, 0, 6, 4, 5, 6, 0, 1, 1, 2, 6, 0, 1, 1, 1, 2, 2, 3, 3, 4, 6, 7, 8, 8, 8, 8,
+        ],
+    ];
+
+    #[test]
+    fn days() {
+        let fish = Ecosystem::new(TEST[0]);
+        fish.zip(TEST.iter())
+            .for_each(|(f, t)| assert_eq!(f, Ecosystem::new(t).fish));
+    }
+
+    #[test]
+    fn long_test() {
+        let mut fish = Ecosystem::new(TEST[0]);
+        assert_eq!(fish.nth(256).unwrap().iter().sum::<usize>(), 26984457539)
+    }
+
+    #[test]
+    fn overflow() {
+        let days = Ecosystem::new(TEST[0]).take(1000).count();
+        assert!((400..1000).contains(&days), "{}", days);
+        let crowded = Ecosystem {
+            fish: [usize::MAX >> 20, 0, 0, 0, 0, 0, 0, 0, 0],
+            start: true,
+        };
+        assert!(part1(&crowded).is_ok());
+        assert_eq!(part2(&crowded), Err(TooManyFish(256)));
+        assert_eq!(
+            TooManyFish(256).to_string(),
+            "after 256 days there are too many fish to count"
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use aoc_common::{count_arg, Session};
+
+use crate::Ecosystem;
+
+/// The school, stepped a day at a time.
+pub(crate) struct School {
+    fish: Ecosystem,
+    day: usize,
+    counts: [usize; 9],
+}
+
+impl School {
+    pub(crate) fn new(mut fish: Ecosystem) -> Self {
+        let counts = fish.next().expect("the days never end");
+        Self {
+            fish,
+            day: 0,
+            counts,
+        }
+    }
+}
+
+impl Session for School {
+    fn help(&self) -> &'static str {
+        "step <n>: let n days pass, 1 by default"
+    }
+
+    fn state(&self) -> String {
+        format!(
+            "day {}: {} fish\nby timer: {:?}",
+            self.day,
+            self.counts.iter().sum::<usize>(),
+            self.counts
+        )
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<Option<String>, String> {
+        match command {
+            "step" => {
+                for _ in 0..count_arg(args)? {
+                    self.counts = self.fish.next().ok_or_else(|| {
+                        format!("after day {} there are too many fish to count", self.day)
+                    })?;
+                    self.day += 1;
+                }
+                Ok(None)
+            }
+            _ => Err(format!("unknown command `{}`", command)),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Random crab positions.
+
+use itertools::Itertools;
+use rand::{Rng, RngExt};
+
+/// `size` crabs between 0 and 2000.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let crabs = (0..size.max(1)).map(|_| rng.random_range(0..2000usize)).join(",");
+    format!("{}\n", crabs)
+}
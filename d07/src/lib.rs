@@ -0,0 +1,83 @@
+//! Day 7: The Treachery of Whales, lining up crab submarines with the least fuel.
+#![warn(missing_docs)]
+
+use std::{error::Error, path::Path};
+
+use aoc_common::{parse_comma_list, read_input, ParseError, Solution};
+use itertools::Itertools;
+
+pub mod generate;
+
+/// Reads and parses the crab positions at `path`.
+pub fn parse_file<P: AsRef<Path>>(path: P) -> Result<Vec<isize>, Box<dyn Error>> {
+    Ok(parse_text(&read_input(path)?)?)
+}
+
+/// Comma separated horizontal positions.
+pub fn parse_text(input: &str) -> Result<Vec<isize>, ParseError> {
+    parse_comma_list(Day07::DAY, input)
+}
+
+/// The least fuel to line up, when each step costs 1.
+pub fn part1(data: &[isize]) -> usize {
+    let (min, max) = data
+        .iter()
+        .minmax()
+        .into_option()
+        .unwrap();
+    (*min..=*max)
+        .map(|n| data.iter().map(|d| d.abs_diff(n)).sum())
+        .min()
+        .unwrap()
+}
+
+/// The least fuel to line up, when each step costs 1 more than the one before.
+pub fn part2(data: &[isize]) -> usize {
+    let (min, max) = data
+        .iter()
+        .minmax()
+        .into_option()
+        .unwrap();
+    (*min..=*max)
+        .map(|n| data.iter().map(|d| -> usize {(1..=d.abs_diff(n)).sum()}).sum())
+        .min()
+        .unwrap()
+}
+
+/// The [`Solution`] of day 7.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<isize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_text(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_p1() {
+        assert_eq!(part1(&[16,1,2,0,4,2,7,1,2,14]), 37)
+    }
+
+    #[test]
+    fn test_p2() {
+        assert_eq!(part2(&[16,1,2,0,4,2,7,1,2,14]), 168)
+    }
+}
@@ -1,54 +1,11 @@
-#![feature(int_abs_diff)]
-use std::{fs::read_to_string, io, path::Path};
+use std::error::Error;
 
-use itertools::Itertools;
+use aoc_common::{InputSource, Solution};
+use d07::{parse_text, part1, part2, Day07};
 
-fn parse_file<P: AsRef<Path>>(path: P) -> io::Result<Vec<isize>> {
-    read_to_string(path)?.trim().split(',').map(|n| Ok(n.parse().unwrap())).collect()
-}
-
-fn part1(data: &[isize]) -> usize {
-    let (min, max) = data
-        .iter()
-        .minmax()
-        .into_option()
-        .unwrap();
-    (*min..=*max)
-        .map(|n| data.iter().map(|d| d.abs_diff(n)).sum())
-        .min()
-        .unwrap()
-}
-
-fn part2(data: &[isize]) -> usize {
-    let (min, max) = data
-        .iter()
-        .minmax()
-        .into_option()
-        .unwrap();
-    (*min..=*max)
-        .map(|n| data.iter().map(|d| -> usize {(1..=d.abs_diff(n)).sum()}).sum())
-        .min()
-        .unwrap()
-}
-
-fn main() -> io::Result<()> {
-    let data = parse_file("input.txt")?;
+fn main() -> Result<(), Box<dyn Error>> {
+    let data = parse_text(&InputSource::from_args().read(Day07::DAY)?)?;
     println!("Part 1: {}", part1(&data));
     println!("Part 2: {}", part2(&data));
     Ok(())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_p1() {
-        assert_eq!(part1(&[16,1,2,0,4,2,7,1,2,14]), 37)
-    }
-
-    #[test]
-    fn test_p2() {
-        assert_eq!(part2(&[16,1,2,0,4,2,7,1,2,14]), 168)
-    }
-}
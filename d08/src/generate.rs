@@ -0,0 +1,34 @@
+//! Random scrambled seven segment displays.
+
+use rand::{seq::SliceRandom, Rng, RngExt};
+
+/// The segments of each digit, wired the right way.
+pub(crate) const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+fn scramble<R: Rng + ?Sized>(rng: &mut R, digit: &str, wires: &[char]) -> String {
+    let mut s: Vec<_> = digit.bytes().map(|b| wires[(b - b'a') as usize]).collect();
+    s.shuffle(rng);
+    s.into_iter().collect()
+}
+
+/// `size` entries, each with its own wiring.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut wires: Vec<char> = ('a'..='g').collect();
+            wires.shuffle(rng);
+            let mut digits = DIGITS;
+            digits.shuffle(rng);
+            let patterns: Vec<_> = digits.iter().map(|d| scramble(rng, d, &wires)).collect();
+            let output: Vec<_> = (0..4)
+                .map(|_| {
+                    let digit = DIGITS[rng.random_range(0..10)];
+                    scramble(rng, digit, &wires)
+                })
+                .collect();
+            format!("{} | {}\n", patterns.join(" "), output.join(" "))
+        })
+        .collect()
+}
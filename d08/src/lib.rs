@@ -0,0 +1,374 @@
+//! Day 8: Seven Segment Search, untangling the wiring of seven segment displays.
+#![warn(missing_docs)]
+
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    str::FromStr,
+};
+
+use aoc_common::{parse_lines_with, ParseError, Solution};
+
+pub mod generate;
+
+type Segment = [bool; 7];
+
+// const SEGMENTS: [Segment; 10] = [
+//     //  aaaaaa, bbbbb, ccccc, ddddd, eeeee, fffff, ggggg
+//     [ true,  true,  true, false,  true,  true,  true],
+//     [false, false,  true, false, false,  true, false],
+//     [ true, false,  true,  true,  true, false,  true],
+//     [ true, false,  true,  true, false,  true,  true],
+//     [false,  true,  true,  true, false,  true, false],
+//     [ true,  true, false,  true, false,  true,  true],
+//     [ true,  true, false,  true,  true,  true,  true],
+//     [ true, false,  true, false, false,  true, false],
+//     [ true,  true,  true,  true,  true,  true,  true],
+//     [ true,  true,  true,  true, false,  true,  true],
+// ];
+
+fn segment(line: &str, s: &str) -> Result<Segment, ParseError> {
+    let mut seg = [false; 7];
+    for (i, c) in s.char_indices() {
+        let n = match c {
+            'a' => 0,
+            'b' => 1,
+            'c' => 2,
+            'd' => 3,
+            'e' => 4,
+            'f' => 5,
+            'g' => 6,
+            n => {
+                return Err(ParseError::at(
+                    Day08::DAY,
+                    line,
+                    &s[i..],
+                    format!("unknown segment letter `{}`", n),
+                ))
+            }
+        };
+        seg[n] = true;
+    }
+    Ok(seg)
+}
+
+fn patterns<const N: usize>(line: &str, s: &str) -> Result<[Segment; N], ParseError> {
+    let patterns = s
+        .split_ascii_whitespace()
+        .map(|p| segment(line, p))
+        .collect::<Result<Vec<_>, _>>()?;
+    let len = patterns.len();
+    patterns.try_into().map_err(|_| {
+        ParseError::at(
+            Day08::DAY,
+            line,
+            s.trim(),
+            format!("expected {} patterns, found {}", N, len),
+        )
+    })
+}
+
+/// One display: the ten unique patterns and the four digits of its output.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Segments {
+    input: [Segment; 10],
+    output: [Segment; 4],
+}
+
+impl Segments {
+    fn get_lens(&self, s: usize) -> Vec<Segment> {
+        if !(2..=7).contains(&s) {
+            panic!("Segments are from number 0 to 9 inclusive")
+        }
+        self.input
+            .iter()
+            .filter(|n| segment_len(n) == s)
+            .copied()
+            .collect()
+    }
+
+    // [6, 2, 5, 5, 4, 5, 6, 3, 7, 6]
+    // |0, 1, 2, 3, 4, 5, 6, 7, 8, 9|
+    fn create_matching(&self) -> HashMap<Segment, usize> {
+        let mut map = HashMap::new();
+        // one
+        let seg_one = self.get_lens(2)[0];
+        map.insert(seg_one, 1);
+        let one = seg_pos_to_numbers(&seg_one);
+        // seven
+        let seg_seven = self.get_lens(3)[0];
+        map.insert(seg_seven, 7);
+        // six
+        let mut six_len = self.get_lens(6);
+        let seg_six = six_len.remove(
+            six_len
+                .iter()
+                .map(seg_pos_to_numbers)
+                .enumerate()
+                .filter(|(_, s)| !one.is_subset(s))
+                .map(|(n, _)| n)
+                .next()
+                .unwrap(),
+        );
+        map.insert(seg_six, 6);
+        let segment_c = *(0..7)
+            .collect::<HashSet<_>>()
+            .difference(&seg_pos_to_numbers(&seg_six))
+            .next()
+            .unwrap();
+        // five
+        let mut five_len = self.get_lens(5);
+        let seg_five = five_len.remove(
+            five_len
+                .iter()
+                .map(seg_pos_to_numbers)
+                .enumerate()
+                .filter(|(_, s)| !s.contains(&segment_c))
+                .map(|(n, _)| n)
+                .next()
+                .unwrap(),
+        );
+        map.insert(seg_five, 5);
+        let mut seg_five_with_c = seg_pos_to_numbers(&seg_five);
+        seg_five_with_c.insert(segment_c);
+        let segment_e = *(0..7)
+            .collect::<HashSet<_>>()
+            .difference(&seg_five_with_c)
+            .next()
+            .unwrap();
+        // nine
+        let seg_nine = six_len.remove(
+            six_len
+                .iter()
+                .map(seg_pos_to_numbers)
+                .enumerate()
+                .filter(|(_, s)| !s.contains(&segment_e))
+                .map(|(n, _)| n)
+                .next()
+                .unwrap(),
+        );
+        map.insert(seg_nine, 9);
+        // zero
+        let seg_zero = six_len.remove(0);
+        map.insert(seg_zero, 0);
+        // eight
+        map.insert(self.get_lens(7)[0], 8);
+        // four
+        map.insert(self.get_lens(4)[0], 4);
+        // three
+        let seg_three = five_len.remove(
+            five_len
+                .iter()
+                .map(seg_pos_to_numbers)
+                .enumerate()
+                .filter(|(_, s)| !s.contains(&segment_e))
+                .map(|(n, _)| n)
+                .next()
+                .unwrap(),
+        );
+        map.insert(seg_three, 3);
+        // two
+        map.insert(five_len[0], 2);
+        map
+    }
+
+    /// Works out the wiring from the ten patterns and reads the output.
+    ///
+    /// # Panics
+    ///
+    /// When the patterns aren't the ten digits under some wiring.
+    pub fn get_number(&self) -> usize {
+        let matching = self.create_matching();
+        let mut ans = 0;
+        self.output.iter().for_each(|o| {
+            ans *= 10;
+            ans += matching.get(o).unwrap();
+        });
+        ans
+    }
+}
+
+impl FromStr for Segments {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (input, output) = s.split_once('|').ok_or_else(|| {
+            ParseError::at(Day08::DAY, s, s.trim(), "missing `|` symbol")
+        })?;
+        Ok(Self {
+            input: patterns(s, input)?,
+            output: patterns(s, output)?,
+        })
+    }
+}
+
+/// One display per line, `<10 patterns> | <4 digits>`.
+pub fn parse_text(text: &str) -> Result<Vec<Segments>, ParseError> {
+    parse_lines_with(text, str::parse)
+}
+
+fn segment_len(seg: &Segment) -> usize {
+    seg.iter().filter(|&n| *n).count()
+}
+
+fn unique(seg: &Segment) -> bool {
+    let unique_lens = [2, 4, 3, 7];
+    let len = segment_len(seg);
+    unique_lens.contains(&len)
+}
+
+/// How many output digits are a 1, 4, 7 or 8, which use a unique number of segments.
+pub fn part1(data: &[Segments]) -> usize {
+    data.iter()
+        .map(|s| s.output.iter().map(unique).filter(|n| *n).count())
+        .sum()
+}
+
+fn seg_pos_to_numbers(seg: &Segment) -> HashSet<usize> {
+    seg.iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, s)| *s)
+        .map(|(n, _)| n)
+        .collect()
+}
+
+/// The sum of every output value.
+pub fn part2(data: &[Segments]) -> usize {
+    data.iter().map(|s| s.get_number()).sum()
+}
+
+/// The [`Solution`] of day 8.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Vec<Segments>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_text(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{array::uniform4, prelude::*, sample::Index};
+
+    use super::{generate::DIGITS, *};
+
+    const EXAMPLE: &str = "\
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce
+";
+
+    #[test]
+    fn test_first() {
+        let data = parse_text(EXAMPLE).unwrap();
+        assert_eq!(part1(&data), 26)
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = parse_text(EXAMPLE).unwrap();
+        assert_eq!(part2(&data), 61229)
+    }
+
+    #[test]
+    fn test_second() {
+        let data = parse_text(EXAMPLE).unwrap();
+        let ans = [8394, 9781, 1197, 9361, 4873, 8418, 4548, 1625, 8717, 4315];
+        data.iter()
+            .map(Segments::get_number)
+            .zip(ans)
+            .enumerate()
+            .for_each(|(n, (actual, expected))| {
+                assert_eq!(actual, expected, "number {} is wrong", n);
+            })
+    }
+
+    fn wired(digit: usize, wires: &[char]) -> String {
+        DIGITS[digit]
+            .bytes()
+            .map(|b| wires[(b - b'a') as usize])
+            .collect()
+    }
+
+    fn lit(pattern: &str) -> Segment {
+        let mut seg = [false; 7];
+        pattern.bytes().for_each(|b| seg[(b - b'a') as usize] = true);
+        seg
+    }
+
+    /// A display with a random wiring, as text, parsed by hand and with its
+    /// output value.
+    fn display() -> impl Strategy<Value = (String, Segments, usize)> {
+        let wires = Just(('a'..='g').collect::<Vec<_>>()).prop_shuffle();
+        let order = Just((0..10).collect::<Vec<_>>()).prop_shuffle();
+        (wires, order, uniform4(0..10usize)).prop_map(|(wires, order, output)| {
+            let input = order.iter().map(|&d| wired(d, &wires)).collect::<Vec<_>>();
+            let shown = output.iter().map(|&d| wired(d, &wires)).collect::<Vec<_>>();
+            let text = format!("{} | {}", input.join(" "), shown.join(" "));
+            let segments = Segments {
+                input: std::array::from_fn(|i| lit(&input[i])),
+                output: std::array::from_fn(|i| lit(&shown[i])),
+            };
+            (text, segments, output.iter().fold(0, |n, d| n * 10 + d))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_parse(display in display()) {
+            let (text, segments, value) = display;
+            let parsed = text.parse::<Segments>().unwrap();
+            prop_assert_eq!(parsed.get_number(), value);
+            prop_assert_eq!(parsed, segments);
+        }
+
+        #[test]
+        fn prop_missing_pattern(display in display(), i in any::<Index>()) {
+            let words: Vec<_> = display.0.split(' ').collect();
+            let i = i.index(words.len());
+            prop_assume!(words[i] != "|");
+            let broken = [&words[..i], &words[i + 1..]].concat().join(" ");
+            prop_assert!(broken.parse::<Segments>().is_err(), "{}", broken);
+        }
+
+        #[test]
+        fn prop_bad_letter(display in display(), i in any::<Index>(), c in "[h-zA-G0-9]") {
+            let letters: Vec<_> = display.0.match_indices(char::is_alphabetic).map(|(i, _)| i).collect();
+            let i = letters[i.index(letters.len())];
+            let broken = format!("{}{}{}", &display.0[..i], c, &display.0[i + 1..]);
+            prop_assert!(broken.parse::<Segments>().is_err(), "{}", broken);
+        }
+
+        #[test]
+        fn prop_no_separator(display in display()) {
+            prop_assert!(display.0.replace('|', "").parse::<Segments>().is_err());
+        }
+
+        #[test]
+        fn prop_garbage_does_not_panic(text in "[a-h |]{0,80}|\\PC{0,40}") {
+            let _ = text.parse::<Segments>();
+        }
+    }
+}
@@ -0,0 +1,198 @@
+//! Day 9: Smoke Basin, finding the low points and basins of a height map.
+#![warn(missing_docs)]
+
+use std::{cell::RefCell, collections::HashSet, error::Error};
+
+use aoc_common::{Cell, Colour, Frame, Grid, Image, ParseError, Picture, Solution, Visualize};
+
+pub mod generate;
+
+/// One row of height digits per line.
+pub fn parse_grid(file: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::from_digits(Day09::DAY, file)
+}
+
+/// The sum of the risk levels, one more than the height, of every low point.
+pub fn part1(data: &Grid<usize>) -> usize {
+    data.iter()
+        .filter(|((x, y), value)| data.neighbours4(*x, *y).all(|p| **value < data[p]))
+        .map(|(_, value)| value + 1)
+        .sum()
+}
+
+fn basin(data: &Grid<usize>, x_orig: usize, y_orig: usize, searched: &RefCell<HashSet<(usize, usize)>>) -> usize {
+    searched.borrow_mut().insert((x_orig, y_orig));
+    let neighbours: usize = data
+        .neighbours4(x_orig, y_orig)
+        .filter(|p| searched.borrow_mut().insert(*p))
+        .map(|(x, y)| basin(data, x, y, searched))
+        .sum();
+    neighbours + 1
+}
+
+/// The product of the sizes of the three largest basins.
+pub fn part2(data: &Grid<usize>) -> usize {
+    let points = data
+        .iter()
+        .filter(|(_, value)| **value == 9)
+        .map(|(p, _)| p)
+        .collect::<HashSet<_>>();
+    let cell = RefCell::new(points);
+    let mut basins = data
+        .positions()
+        .filter(|p| !cell.borrow().contains(p))
+        .map(|(x, y)| basin(data, x, y, &cell))
+        .collect::<Vec<_>>();
+    basins.sort_unstable();
+    basins.iter().rev().take(3).product()
+}
+
+/// The low points, and for every position the index of the low point whose
+/// basin it is in.
+fn basins(data: &Grid<usize>) -> (Vec<(usize, usize)>, Grid<Option<usize>>) {
+    let low = data
+        .iter()
+        .filter(|((x, y), value)| data.neighbours4(*x, *y).all(|p| **value < data[p]))
+        .map(|(p, _)| p)
+        .collect::<Vec<_>>();
+    let mut labels = Grid::filled(data.width(), data.height(), None);
+    for (i, &start) in low.iter().enumerate() {
+        let mut stack = vec![start];
+        while let Some(p) = stack.pop() {
+            if data[p] == 9 || labels[p].is_some() {
+                continue;
+            }
+            labels[p] = Some(i);
+            stack.extend(data.neighbours4(p.0, p.1));
+        }
+    }
+    (low, labels)
+}
+
+/// The height map with the low points in red, then once more for every
+/// basin, each basin in its own colour. Heights of 9 are drawn as `#`.
+pub fn frames(data: &Grid<usize>) -> Vec<Frame> {
+    let mut cells = data.map(|&h| match h {
+        9 => Cell::from('#'),
+        h => Cell::from(char::from_digit(h as u32, 10).unwrap_or('+')),
+    });
+    let (low, labels) = basins(data);
+    for &p in &low {
+        cells[p].colour = Some(Colour::Red);
+    }
+    let mut frames =
+        vec![Frame::new(&cells, |&c| c).with_caption(format!("{} low points", low.len()))];
+    for i in 0..low.len() {
+        let basin = labels
+            .iter()
+            .filter(|(_, l)| **l == Some(i))
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>();
+        for &p in &basin {
+            cells[p].colour = Some(Colour::nth(i));
+        }
+        let caption = format!("basin {} of {}: size {}", i + 1, low.len(), basin.len());
+        frames.push(Frame::new(&cells, |&c| c).with_caption(caption));
+    }
+    frames
+}
+
+/// Every basin in its own colour, with the low points white and the heights
+/// of 9 black.
+pub fn basin_image(data: &Grid<usize>) -> Image {
+    let (low, labels) = basins(data);
+    let mut pixels = labels.map(|l| match l {
+        Some(i) => Colour::nth(*i).rgb(),
+        None => [0, 0, 0],
+    });
+    for p in low {
+        pixels[p] = [255, 255, 255];
+    }
+    Image::Rgb(pixels)
+}
+
+/// The [`Solution`] of day 9.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Grid<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_grid(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input))
+    }
+}
+
+impl Visualize for Day09 {
+    fn frames(input: &Self::Input<'_>) -> Result<Vec<Frame>, Box<dyn Error>> {
+        Ok(frames(input))
+    }
+}
+
+impl Picture for Day09 {
+    fn image(input: &Self::Input<'_>) -> Result<Image, Box<dyn Error>> {
+        Ok(basin_image(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const EXAMPLE: &str = "\
+2199943210
+3987894921
+9856789892
+8767896789
+9899965678
+";
+    #[test]
+    fn test_part1() {
+        let data = parse_grid(EXAMPLE).unwrap();
+        assert_eq!(part1(&data), 15)
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = parse_grid(EXAMPLE).unwrap();
+        assert_eq!(part2(&data), 1134)
+    }
+
+    #[test]
+    fn test_frames() {
+        let data = parse_grid(EXAMPLE).unwrap();
+        let frames = frames(&data);
+        assert_eq!(frames.len(), 5);
+        assert_eq!(frames[0].caption(), "4 low points");
+        assert_eq!(frames[0].grid_text().lines().next(), Some("21###43210"));
+        assert_eq!(frames[4].caption(), "basin 4 of 4: size 9");
+        let coloured = frames[4]
+            .cells()
+            .values()
+            .filter(|c| c.colour.is_some())
+            .count();
+        assert_eq!(coloured, 3 + 9 + 14 + 9);
+    }
+
+    #[test]
+    fn test_basin_image() {
+        let data = parse_grid(EXAMPLE).unwrap();
+        let Image::Rgb(pixels) = basin_image(&data) else {
+            panic!("the basins are in colour");
+        };
+        let white = [255, 255, 255];
+        assert_eq!(pixels.row(0)[..3], [Colour::nth(0).rgb(), white, [0, 0, 0]]);
+        let third = pixels.values().filter(|p| **p == Colour::nth(2).rgb()).count();
+        assert_eq!(third, 14 - 1);
+    }
+}
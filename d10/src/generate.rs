@@ -0,0 +1,49 @@
+//! Random navigation subsystem lines.
+
+use rand::{Rng, RngExt};
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// `size` lines, each either corrupted or incomplete. An odd number of them
+/// is incomplete so that part 2 has a middle score.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    let mut incomplete = rng.random_range(0..size) | 1;
+    if incomplete > size {
+        incomplete -= 2;
+    }
+    (0..size)
+        .map(|i| {
+            let mut line = String::new();
+            let mut open = Vec::new();
+            for _ in 0..rng.random_range(4..40) {
+                match open.last() {
+                    Some(&close) if rng.random_bool(0.4) => {
+                        line.push(close);
+                        open.pop();
+                    }
+                    _ => {
+                        let (o, c) = PAIRS[rng.random_range(0..4)];
+                        line.push(o);
+                        open.push(c);
+                    }
+                }
+            }
+            if open.is_empty() {
+                let (o, c) = PAIRS[rng.random_range(0..4)];
+                line.push(o);
+                open.push(c);
+            }
+            if i >= incomplete {
+                let wrong = loop {
+                    let (_, c) = PAIRS[rng.random_range(0..4)];
+                    if open.last() != Some(&c) {
+                        break c;
+                    }
+                };
+                line.push(wrong);
+            }
+            line + "\n"
+        })
+        .collect()
+}
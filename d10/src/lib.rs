@@ -0,0 +1,189 @@
+//! Day 10: Syntax Scoring, checking lines of nested brackets.
+#![warn(missing_docs)]
+
+use std::error::Error;
+
+use aoc_common::{parse_lines_with, ParseError, Solution};
+
+pub mod generate;
+
+/// One bracket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Chunk {
+    /// Which kind of bracket it is.
+    pub style: Style,
+    /// Whether it opens or closes a chunk.
+    pub state: State,
+}
+
+/// The kinds of brackets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Round, `()`
+    RB,
+    /// Square, `[]`
+    SB,
+    /// Angle, `<>`
+    AB,
+    /// Curly, `{}`
+    CB,
+}
+
+impl Style {
+    /// The syntax error score of a corrupted line that closes with this bracket.
+    pub fn score(&self) -> usize {
+        match self {
+            Style::RB => 3,
+            Style::SB => 57,
+            Style::CB => 1197,
+            Style::AB => 25137,
+        }
+    }
+
+    /// The points for closing this bracket when completing a line.
+    pub fn points(&self) -> usize {
+        match self {
+            Style::RB => 1,
+            Style::SB => 2,
+            Style::CB => 3,
+            Style::AB => 4,
+        }
+    }
+}
+
+/// Whether a bracket opens or closes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    /// `(`, `[`, `<` or `{`
+    Open,
+    /// `)`, `]`, `>` or `}`
+    Closed,
+}
+
+fn char_to_chunk(line: &str, i: usize, c: char) -> Result<Chunk, ParseError> {
+    Ok(match c {
+        '(' => Chunk{style: Style::RB, state: State::Open},
+        '[' => Chunk{style: Style::SB, state: State::Open},
+        '<' => Chunk{style: Style::AB, state: State::Open},
+        '{' => Chunk{style: Style::CB, state: State::Open},
+        '}' => Chunk{style: Style::CB, state: State::Closed},
+        '>' => Chunk{style: Style::AB, state: State::Closed},
+        ']' => Chunk{style: Style::SB, state: State::Closed},
+        ')' => Chunk{style: Style::RB, state: State::Closed},
+        n => {
+            return Err(ParseError::at(
+                Day10::DAY,
+                line,
+                &line[i..],
+                format!("expected a bracket, found `{}`", n),
+            ))
+        }
+    })
+}
+
+/// One line of brackets per line.
+pub fn parse_input(input: &str) -> Result<Vec<Vec<Chunk>>, ParseError> {
+    parse_lines_with(input, |l| {
+        l.trim_end()
+            .char_indices()
+            .map(|(i, c)| char_to_chunk(l, i, c))
+            .collect()
+    })
+}
+
+/// The total syntax error score of the corrupted lines.
+pub fn part1(input: &[Vec<Chunk>]) -> usize {
+    let mut score = 0;
+    for line in input {
+        let mut stack = Vec::new();
+        for c in line {
+            match c.state {
+                State::Open => {
+                    stack.push(c.style);
+                },
+                State::Closed => {
+                    if stack.pop() != Some(c.style) {
+                        score += c.style.score();
+                        break;
+                    }
+                },
+            };
+        }
+    };
+    score
+}
+
+/// The middle completion score of the incomplete lines, 0 without any.
+pub fn part2(input: &[Vec<Chunk>]) -> usize {
+    let mut points: Vec<_> = input
+        .iter()
+        .filter_map(|line| {
+            let mut stack = Vec::new();
+            for c in line {
+                match c.state {
+                    State::Open => {
+                        stack.push(c.style);
+                    },
+                    State::Closed => {
+                        if stack.pop() != Some(c.style) {
+                            return None
+                        }
+                    },
+                };
+            }
+            Some(stack)
+        })
+        .map(|mut rest| std::iter::from_fn(|| rest.pop()).fold(0, |a, n| a * 5 + n.points()))
+        .collect();
+    points.sort_unstable();
+    points.get(points.len() / 2).copied().unwrap_or(0)
+}
+
+/// The [`Solution`] of day 10.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<Vec<Chunk>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]
+";
+
+    #[test]
+    fn test_part1() {
+        let data = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part1(&data), 26397);
+        let corrupted = parse_input("(]\n)\n").unwrap();
+        assert_eq!((part1(&corrupted), part2(&corrupted)), (57 + 3, 0));
+    }
+}
@@ -0,0 +1,34 @@
+//! Random octopus energy levels.
+
+use rand::{Rng, RngExt};
+
+use crate::parse_input;
+
+fn grid<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size)
+                .map(|_| char::from(b'0' + rng.random_range(0..10)))
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
+
+/// A `size` by `size` grid of energy levels that synchronises.
+///
+/// Part 2 never ends on a grid that doesn't, and most large random grids
+/// don't, so candidates are simulated first. When none of them synchronises
+/// within 1000 steps a grid of one level is returned, which always does.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    for _ in 0..20 {
+        let candidate = grid(rng, size);
+        let octopuses = parse_input(&candidate).expect("digits only");
+        if octopuses.take(1000).any(|n| n == size * size) {
+            return candidate;
+        }
+    }
+    let level = char::from(b'0' + rng.random_range(0..10));
+    (0..size).map(|_| level.to_string().repeat(size) + "\n").collect()
+}
@@ -0,0 +1,315 @@
+//! Day 11: Dumbo Octopus, simulating flashing octopuses.
+#![warn(missing_docs)]
+
+use std::{collections::HashMap, error::Error};
+
+use aoc_common::{Cell, Colour, Explore, Frame, Grid, ParseError, Session, Solution, Visualize};
+
+pub mod generate;
+mod repl;
+
+/// The energy levels of a grid of octopuses.
+///
+/// Each step of the iterator advances the grid and gives how many flashed.
+#[derive(Debug, Clone)]
+pub struct OctoGrid {
+    grid: Grid<usize>,
+}
+
+impl OctoGrid {
+    /// The energy level of every octopus.
+    pub fn grid(&self) -> &Grid<usize> {
+        &self.grid
+    }
+
+    fn frame(&self, caption: String) -> Frame {
+        Frame::new(&self.grid, |&n| match n {
+            0 => Cell::new('0', Colour::Yellow),
+            n => Cell::from(char::from_digit(n as u32, 10).unwrap_or('+')),
+        })
+        .with_caption(caption)
+    }
+}
+
+impl Iterator for OctoGrid {
+    type Item = usize;
+    fn next(&mut self) -> Option<Self::Item> {
+        let _span = aoc_common::trace_span!("d11 step");
+        self.grid.values_mut().for_each(|n| *n += 1);
+        let mut flashes = HashMap::new();
+        let mut last_len = usize::MAX;
+        let mut len = 0;
+        let mut passes = 0;
+        while last_len != len {
+            passes += 1;
+            last_len = len;
+            for ((x, y), value) in self.grid.iter() {
+                if *value > 9 {
+                    flashes.entry((x, y)).or_insert_with(|| self.grid.neighbours8(x, y));
+                }
+            }
+            for i in flashes.values_mut() {
+                for p in i {
+                    self.grid[p] += 1
+                }
+            }
+            len = self.grid.values().filter(|&n| *n > 9).count();
+        }
+        aoc_common::trace_count!("d11 fixed-point passes", passes);
+        self.grid
+            .values_mut()
+            .filter(|n| **n > 9)
+            .for_each(|n| *n = 0);
+        Some(len)
+    }
+}
+
+/// How many flashes there are in the first 100 steps.
+pub fn part1(grid: OctoGrid) -> usize {
+    grid.take(100).sum()
+}
+
+/// The first step on which every octopus flashes.
+///
+/// Never returns for a grid that doesn't synchronise.
+pub fn part2(grid: OctoGrid) -> usize {
+    let size = grid.grid.width() * grid.grid.height();
+    grid.take_while(|n| *n < size).count() + 1
+}
+
+/// The grid before the first step and after every step, until all the
+/// octopuses flash at once or `max_steps` have passed. Octopuses that just
+/// flashed are yellow.
+pub fn frames(grid: &OctoGrid, max_steps: usize) -> Vec<Frame> {
+    let size = grid.grid.width() * grid.grid.height();
+    let mut frames = vec![grid.frame("step 0".to_string())];
+    let mut grid = grid.clone();
+    for step in 1..=max_steps {
+        let flashes = grid.next().expect("the steps never end");
+        frames.push(grid.frame(format!("step {}: {} flashed", step, flashes)));
+        if flashes == size {
+            break;
+        }
+    }
+    frames
+}
+
+/// One row of energy digits per line.
+pub fn parse_input(input: &str) -> Result<OctoGrid, ParseError> {
+    Ok(OctoGrid {
+        grid: Grid::from_digits(Day11::DAY, input)?,
+    })
+}
+
+/// The [`Solution`] of day 11.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = OctoGrid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input.clone()))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input.clone()))
+    }
+}
+
+impl Visualize for Day11 {
+    fn frames(input: &Self::Input<'_>) -> Result<Vec<Frame>, Box<dyn Error>> {
+        Ok(frames(input, 1000))
+    }
+}
+
+impl Explore for Day11 {
+    fn session(input: Self::Input<'_>) -> Box<dyn Session + '_> {
+        Box::new(repl::Octopuses::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: &[&str] = &[
+        "\
+5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526
+",
+        "\
+6594254334
+3856965822
+6375667284
+7252447257
+7468496589
+5278635756
+3287952832
+7993992245
+5957959665
+6394862637
+",
+        "\
+8807476555
+5089087054
+8597889608
+8485769600
+8700908800
+6600088989
+6800005943
+0000007456
+9000000876
+8700006848
+",
+        "\
+0050900866
+8500800575
+9900000039
+9700000041
+9935080063
+7712300000
+7911250009
+2211130000
+0421125000
+0021119000
+",
+        "\
+2263031977
+0923031697
+0032221150
+0041111163
+0076191174
+0053411122
+0042361120
+5532241122
+1532247211
+1132230211
+",
+        "\
+4484144000
+2044144000
+2253333493
+1152333274
+1187303285
+1164633233
+1153472231
+6643352233
+2643358322
+2243341322
+",
+        "\
+5595255111
+3155255222
+3364444605
+2263444496
+2298414396
+2275744344
+2264583342
+7754463344
+3754469433
+3354452433
+",
+        "\
+6707366222
+4377366333
+4475555827
+3496655709
+3500625609
+3509955566
+3486694453
+8865585555
+4865580644
+4465574644
+",
+        "\
+7818477333
+5488477444
+5697666949
+4608766830
+4734946730
+4740097688
+6900007564
+0000009666
+8000004755
+6800007755
+",
+        "\
+9060000644
+7800000976
+6900000080
+5840000082
+5858000093
+6962400000
+8021250009
+2221130009
+9111128097
+7911119976
+",
+        "\
+0481112976
+0031112009
+0041112504
+0081111406
+0099111306
+0093511233
+0442361130
+5532252350
+0532250600
+0032240000
+",
+    ];
+    #[test]
+    fn test_interator() {
+        let mut data = parse_input(EXAMPLES[0]).unwrap();
+        for example in EXAMPLES.iter().skip(1) {
+            let expected = example.chars().filter(|c| *c == '0').count();
+            let actual = data.next().unwrap();
+            assert_eq!(data.frame(String::new()).grid_text(), example.trim_end());
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let data = parse_input(EXAMPLES[0]).unwrap();
+        assert_eq!(part1(data.clone()), 1656)
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = parse_input(EXAMPLES[0]).unwrap();
+        assert_eq!(part2(data.clone()), 195)
+    }
+
+    #[test]
+    fn test_frames() {
+        let data = parse_input(EXAMPLES[0]).unwrap();
+        let all = frames(&data, 1000);
+        assert_eq!(all.len(), 196);
+        assert_eq!(all[195].caption(), "step 195: 100 flashed");
+        assert_eq!(all[195].grid_text(), ["0000000000"; 10].join("\n"));
+        assert_eq!(frames(&data, 10).len(), 11);
+    }
+
+    #[test]
+    fn test_range() {
+        let data = parse_input(EXAMPLES[0]).unwrap();
+        assert_eq!(data.grid.neighbours8(0, 2).count(), 5);
+    }
+}
@@ -0,0 +1,51 @@
+use aoc_common::{count_arg, Session};
+
+use crate::OctoGrid;
+
+/// The octopuses, stepped a step at a time.
+pub(crate) struct Octopuses {
+    grid: OctoGrid,
+    step: usize,
+}
+
+impl Octopuses {
+    pub(crate) fn new(grid: OctoGrid) -> Self {
+        Self { grid, step: 0 }
+    }
+}
+
+impl Session for Octopuses {
+    fn help(&self) -> &'static str {
+        "step <n>: take n steps, 1 by default\n\
+         sync: step until every octopus flashes at once, at most 10000 steps"
+    }
+
+    fn state(&self) -> String {
+        format!("step {}\n{}", self.step, self.grid.grid())
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<Option<String>, String> {
+        let size = self.grid.grid().width() * self.grid.grid().height();
+        match command {
+            "step" => {
+                let mut flashes = 0;
+                for _ in 0..count_arg(args)? {
+                    flashes += self.grid.next().expect("the steps never end");
+                    self.step += 1;
+                }
+                Ok(Some(format!("{} flashes", flashes)))
+            }
+            "sync" if args.is_empty() => {
+                for _ in 0..10000 {
+                    self.step += 1;
+                    if self.grid.next() == Some(size) {
+                        return Ok(Some(format!("all flashed in step {}", self.step)));
+                    }
+                }
+                Err("the octopuses did not flash at once in 10000 steps".to_string())
+            }
+            "sync" => Err("`sync` takes no arguments".to_string()),
+            _ => Err(format!("unknown command `{}`", command)),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! Random cave systems.
+
+use std::collections::BTreeSet;
+
+use rand::{seq::IndexedRandom, Rng, RngExt};
+
+fn name<R: Rng + ?Sized>(rng: &mut R, letters: std::ops::RangeInclusive<u8>) -> String {
+    (0..2).map(|_| char::from(rng.random_range(letters.clone()))).collect()
+}
+
+/// About `size` passages between `start`, `end`, small and big caves. Big
+/// caves are never connected to each other, which would give endless paths.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(2);
+    let small: BTreeSet<_> = (0..size / 3 + 1).map(|_| name(rng, b'a'..=b'z')).collect();
+    let big: BTreeSet<_> = (0..size / 6 + 1).map(|_| name(rng, b'A'..=b'Z')).collect();
+    let mut small: Vec<_> = small.into_iter().collect();
+    let big: Vec<_> = big.into_iter().collect();
+    let caves: Vec<_> = small.iter().chain(&big).cloned().collect();
+    small.extend(["start".to_string(), "end".to_string()]);
+
+    let mut edges = BTreeSet::new();
+    edges.insert(("start".to_string(), caves.choose(rng).unwrap().clone()));
+    edges.insert((caves.choose(rng).unwrap().clone(), "end".to_string()));
+    for _ in 0..size * 4 {
+        if edges.len() >= size {
+            break;
+        }
+        let a = small.choose(rng).unwrap();
+        let b = caves.choose(rng).unwrap();
+        if a != b && !edges.contains(&(b.clone(), a.clone())) {
+            edges.insert((a.clone(), b.clone()));
+        }
+    }
+    edges.into_iter().map(|(a, b)| format!("{}-{}\n", a, b)).collect()
+}
@@ -0,0 +1,213 @@
+//! Day 12: Passage Pathing, counting the paths through a cave system.
+#![warn(missing_docs)]
+
+use std::{collections::HashSet, error::Error};
+
+use aoc_common::{parse_lines_with, ParseError, Solution};
+use petgraph::graphmap::UnGraphMap;
+
+pub mod generate;
+
+/// The caves and the passages between them.
+pub type Graph<'a> = UnGraphMap<Node<'a>, ()>;
+
+/// A cave, named by the input.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Node<'a> {
+    name: &'a str,
+    size: Size,
+}
+
+impl<'a> Node<'a> {
+    fn new(name: &'a str, size: Size) -> Self {
+        Self { name, size }
+    }
+
+    /// The cave every path starts in.
+    pub fn start() -> Self {
+        Self::new("start", Size::Small)
+    }
+
+    /// The cave every path ends in.
+    pub fn end() -> Self {
+        Self::new("end", Size::Small)
+    }
+
+    /// The name of the cave, as in the input.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Whether the cave is big or small.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Node<'a> {
+    type Error = String;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        let size = match s {
+            "" => return Err("expected a cave name".to_string()),
+            s if s.chars().all(|c| c.is_ascii_uppercase()) => Size::Large,
+            s if s.chars().all(|c| c.is_ascii_lowercase()) => Size::Small,
+            n => {
+                return Err(format!(
+                    "all letters need to be the same case, found: {}",
+                    n
+                ))
+            }
+        };
+        Ok(Self::new(s, size))
+    }
+}
+
+/// Whether a cave may be visited more than once.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Size {
+    /// An upper case cave, visited any number of times.
+    Large,
+    /// A lower case cave, visited at most once.
+    Small,
+}
+
+fn node<'a>(line: &str, name: &'a str) -> Result<Node<'a>, ParseError> {
+    Node::try_from(name).map_err(|e| ParseError::at(Day12::DAY, line, name, e))
+}
+
+/// One `from-to` passage per line.
+pub fn parse_data(input: &str) -> Result<Graph<'_>, ParseError> {
+    let edges = parse_lines_with(input, |l| {
+        let (a, b) = l.trim().split_once('-').ok_or_else(|| {
+            ParseError::at(Day12::DAY, l, l.trim(), "expected `from-to`")
+        })?;
+        Ok([node(l, a)?, node(l, b)?])
+    })?;
+    let mut graph = UnGraphMap::with_capacity(edges.len() * 2, edges.len());
+    for edge in &edges {
+        for node in edge {
+            graph.add_node(*node);
+        }
+        graph.add_edge(edge[0], edge[1], ());
+    }
+    Ok(graph)
+}
+
+fn traverse_p1<'a, 'b>(node: Node<'a>, mut small: HashSet<Node<'a>>, graph: &Graph<'b>) -> usize
+where
+    'a: 'b,
+{
+    aoc_common::trace_count!("d12 traverse_p1 calls", 1);
+    if node == Node::end() {
+        return 1;
+    }
+    if node.size == Size::Small && !small.insert(node) {
+        return 0;
+    }
+    graph
+        .neighbors(node)
+        .map(|n| traverse_p1(n, small.clone(), graph))
+        .sum()
+}
+
+/// How many paths visit each small cave at most once.
+pub fn part1(graph: &Graph) -> usize {
+    let _span = aoc_common::trace_span!("d12 part1");
+    let small = HashSet::new();
+    traverse_p1(Node::start(), small, graph)
+}
+
+fn traverse_p2<'a, 'b>(
+    node: Node<'a>,
+    mut small: HashSet<Node<'a>>,
+    graph: &Graph<'b>,
+    mut one_twice: bool,
+) -> usize
+where
+    'a: 'b,
+{
+    aoc_common::trace_count!("d12 traverse_p2 calls", 1);
+    if node == Node::end() {
+        return 1;
+    }
+    if node.size == Size::Small && !small.insert(node) {
+        if node == Node::start() || one_twice {
+            return 0;
+        } else {
+            one_twice = true;
+        }
+    }
+    graph
+        .neighbors(node)
+        .map(|n| traverse_p2(n, small.clone(), graph, one_twice))
+        .sum()
+}
+
+/// How many paths visit at most one small cave twice, and the others once.
+pub fn part2(graph: &Graph) -> usize {
+    let _span = aoc_common::trace_span!("d12 part2");
+    let small = HashSet::new();
+    traverse_p2(Node::start(), small, graph, false)
+}
+
+/// The [`Solution`] of day 12.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input<'a> = Graph<'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_data(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW
+";
+
+    #[test]
+    fn test_part1() {
+        let data = parse_data(EXAMPLE).unwrap();
+        assert_eq!(part1(&data), 226);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = parse_data(EXAMPLE).unwrap();
+        assert_eq!(part2(&data), 3509);
+    }
+}
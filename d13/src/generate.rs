@@ -0,0 +1,30 @@
+//! Random transparent paper and fold instructions.
+
+use rand::{Rng, RngExt};
+
+/// `size` dots and a few folds. The dots are made by unfolding points on a
+/// small sheet, so none of them lies on a fold line.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut sheet = [40, 6];
+    let mut dots: Vec<[usize; 2]> = (0..size.max(1))
+        .map(|_| [rng.random_range(0..sheet[0]), rng.random_range(0..sheet[1])])
+        .collect();
+    let mut folds = Vec::new();
+    for _ in 0..rng.random_range(1..=6) {
+        let axis = rng.random_range(0..2);
+        let n = sheet[axis];
+        sheet[axis] = 2 * n + 1;
+        for dot in dots.iter_mut() {
+            if rng.random_bool(0.5) {
+                dot[axis] = 2 * n - dot[axis];
+            }
+        }
+        folds.push(format!("fold along {}={}\n", ["x", "y"][axis], n));
+    }
+    dots.sort_unstable();
+    dots.dedup();
+    let mut s: String = dots.iter().map(|[x, y]| format!("{},{}\n", x, y)).collect();
+    s.push('\n');
+    s.extend(folds.into_iter().rev());
+    s
+}
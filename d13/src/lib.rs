@@ -0,0 +1,425 @@
+//! Day 13: Transparent Origami, folding a sheet of dots into a code.
+#![warn(missing_docs)]
+
+use std::{
+    collections::HashSet,
+    error::Error,
+    fmt::{self, Display},
+};
+
+use aoc_common::{
+    parse_at, Cell, Colour, Explore, Frame, Grid, Image, ParseError, Picture, Session, Solution,
+    Visualize,
+};
+
+pub mod generate;
+mod repl;
+
+/// A fold of the sheet.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    /// Folds the right half onto the left along the line `x = n`.
+    X(usize),
+    /// Folds the bottom half up along the line `y = n`.
+    Y(usize),
+}
+
+/// The `x,y` dots, a blank line, then the `fold along` instructions.
+pub fn parse_input(input: &str) -> Result<(HashSet<[usize; 2]>, Vec<Instruction>), ParseError> {
+    let error =
+        |token: &str, description: &str| ParseError::at(Day13::DAY, input, token, description);
+    let mut lines = input.lines();
+    let points = lines
+        .by_ref()
+        .take_while(|l| !l.trim().is_empty())
+        .map(|l| {
+            let (x, y) = l
+                .trim()
+                .split_once(',')
+                .ok_or_else(|| error(l, "expected `x,y`"))?;
+            Ok([
+                parse_at(Day13::DAY, input, x)?,
+                parse_at(Day13::DAY, input, y)?,
+            ])
+        })
+        .collect::<Result<_, ParseError>>()?;
+    let instructions = lines
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let (direction, line) = l
+                .trim()
+                .strip_prefix("fold along ")
+                .and_then(|f| f.split_once('='))
+                .ok_or_else(|| error(l, "expected `fold along <x|y>=<n>`"))?;
+            match direction {
+                "x" => Ok(Instruction::X(parse_at(Day13::DAY, input, line)?)),
+                "y" => Ok(Instruction::Y(parse_at(Day13::DAY, input, line)?)),
+                n => Err(error(n, &format!("expected `x` or `y`, found `{}`", n))),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match instructions.is_empty() {
+        true => Err(error(
+            &input[input.len()..],
+            "expected fold instructions after a blank line",
+        )),
+        false => Ok((points, instructions)),
+    }
+}
+
+/// The most cells a sheet may have to be drawn.
+const MAX_CELLS: usize = 1 << 24;
+
+/// Why the dots can't be folded or drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SheetError {
+    /// Folding along the line would take the dots this far away past the
+    /// left or top edge.
+    PastEdge {
+        /// The fold.
+        instruction: Instruction,
+        /// The farthest dot from the edge along the fold's axis.
+        far: usize,
+    },
+    /// The sheet has more than [`MAX_CELLS`] cells.
+    TooBig {
+        /// Up to the rightmost dot.
+        width: usize,
+        /// Up to the lowest dot.
+        height: usize,
+    },
+}
+
+impl Display for SheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SheetError::PastEdge { instruction, far } => {
+                let (name, n) = match instruction {
+                    Instruction::X(n) => ("x", n),
+                    Instruction::Y(n) => ("y", n),
+                };
+                write!(
+                    f,
+                    "folding along {}={} would take the dots at {}={} past the edge",
+                    name, n, name, far
+                )
+            }
+            SheetError::TooBig { width, height } => {
+                write!(f, "the sheet is {}x{}, too big to draw", width, height)
+            }
+        }
+    }
+}
+
+impl Error for SheetError {}
+
+fn fold(
+    data: &HashSet<[usize; 2]>,
+    instruction: Instruction,
+) -> Result<HashSet<[usize; 2]>, SheetError> {
+    let (axis, n) = match instruction {
+        Instruction::X(n) => (0, n),
+        Instruction::Y(n) => (1, n),
+    };
+    match data.iter().map(|p| p[axis]).max() {
+        Some(far) if far > n.saturating_mul(2) => Err(SheetError::PastEdge { instruction, far }),
+        _ => Ok(data
+            .iter()
+            .map(|&p| match instruction {
+                Instruction::X(n) if p[0] > n => [n - (p[0] - n), p[1]],
+                Instruction::Y(n) if p[1] > n => [p[0], n - (p[1] - n)],
+                _ => p,
+            })
+            .collect()),
+    }
+}
+
+/// The width and height of the sheet from the top left corner to the last
+/// dot.
+fn bounds(data: &HashSet<[usize; 2]>) -> (usize, usize) {
+    let shape = data
+        .iter()
+        .fold([0, 0], |p, n| [p[0].max(n[0]), p[1].max(n[1])]);
+    (shape[0].saturating_add(1), shape[1].saturating_add(1))
+}
+
+/// Fails for sheets of more than [`MAX_CELLS`], before anything is drawn.
+fn check_size(data: &HashSet<[usize; 2]>) -> Result<(), SheetError> {
+    let (width, height) = bounds(data);
+    match width.checked_mul(height).is_some_and(|n| n <= MAX_CELLS) {
+        true => Ok(()),
+        false => Err(SheetError::TooBig { width, height }),
+    }
+}
+
+/// The sheet from the top left corner to the last dot, `#` for a dot.
+fn frame(data: &HashSet<[usize; 2]>) -> Result<Frame, SheetError> {
+    check_size(data)?;
+    let (width, height) = bounds(data);
+    let mut sheet = Grid::filled(width, height, false);
+    data.iter().for_each(|p| sheet[(p[0], p[1])] = true);
+    Ok(Frame::new(&sheet, |&dot| match dot {
+        true => Cell::new('#', Colour::Yellow),
+        false => Cell::from(' '),
+    }))
+}
+
+/// The dots after every fold of `instructions`.
+fn fold_all(
+    data: &HashSet<[usize; 2]>,
+    instructions: &[Instruction],
+) -> Result<HashSet<[usize; 2]>, SheetError> {
+    instructions
+        .iter()
+        .try_fold(data.clone(), |data, &instruction| fold(&data, instruction))
+}
+
+/// How many dots are visible after the first fold.
+pub fn part1(
+    data: &HashSet<[usize; 2]>,
+    instructions: &[Instruction],
+) -> Result<usize, SheetError> {
+    Ok(fold(data, instructions[0])?.len())
+}
+
+/// The code drawn by the dots after every fold, `#` for a dot.
+pub fn part2(
+    data: &HashSet<[usize; 2]>,
+    instructions: &[Instruction],
+) -> Result<String, SheetError> {
+    Ok(frame(&fold_all(data, instructions)?)?.grid_text())
+}
+
+/// The dots in black on white.
+pub fn sheet_image(data: &HashSet<[usize; 2]>) -> Result<Image, SheetError> {
+    check_size(data)?;
+    Ok(Image::points(data.iter().map(|p| (p[0], p[1]))))
+}
+
+/// The sheet before the first fold and after every fold.
+pub fn frames(
+    data: &HashSet<[usize; 2]>,
+    instructions: &[Instruction],
+) -> Result<Vec<Frame>, SheetError> {
+    let mut data = data.clone();
+    let mut frames = vec![frame(&data)?.with_caption(format!("{} dots", data.len()))];
+    for &instruction in instructions {
+        data = fold(&data, instruction)?;
+        let caption = match instruction {
+            Instruction::X(n) => format!("fold along x={}: {} dots", n, data.len()),
+            Instruction::Y(n) => format!("fold along y={}: {} dots", n, data.len()),
+        };
+        frames.push(frame(&data)?.with_caption(caption));
+    }
+    Ok(frames)
+}
+
+/// The [`Solution`] of day 13.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input<'a> = (HashSet<[usize; 2]>, Vec<Instruction>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(&input.0, &input.1)?)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(&input.0, &input.1)?)
+    }
+}
+
+impl Visualize for Day13 {
+    fn frames(input: &Self::Input<'_>) -> Result<Vec<Frame>, Box<dyn Error>> {
+        Ok(frames(&input.0, &input.1)?)
+    }
+}
+
+impl Picture for Day13 {
+    fn image(input: &Self::Input<'_>) -> Result<Image, Box<dyn Error>> {
+        Ok(sheet_image(&fold_all(&input.0, &input.1)?)?)
+    }
+}
+
+impl Explore for Day13 {
+    fn session(input: Self::Input<'_>) -> Box<dyn Session + '_> {
+        Box::new(repl::Sheet::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{
+        collection::{hash_set, vec},
+        prelude::*,
+        sample::Index,
+    };
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5
+";
+
+    #[test]
+    fn test_part1() {
+        let (data, instructions) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part1(&data, &instructions), Ok(17));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (data, instructions) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(
+            part2(&data, &instructions).unwrap(),
+            "#####\n#   #\n#   #\n#   #\n#####"
+        );
+    }
+
+    #[test]
+    fn test_frames() {
+        let (data, instructions) = parse_input(EXAMPLE).unwrap();
+        let frames = frames(&data, &instructions).unwrap();
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[1].caption(), "fold along y=7: 17 dots");
+        assert_eq!(
+            (frames[0].cells().width(), frames[0].cells().height()),
+            (11, 15)
+        );
+        assert_eq!(frames[2].grid_text(), "#####\n#   #\n#   #\n#   #\n#####");
+    }
+
+    #[test]
+    fn test_sheet_image() {
+        let (data, _) = parse_input(EXAMPLE).unwrap();
+        let Ok(Image::Grey(pixels)) = sheet_image(&data) else {
+            panic!("the sheet is grey");
+        };
+        assert_eq!((pixels.width(), pixels.height()), (11, 15));
+        assert_eq!(pixels.values().filter(|&&p| p == 0).count(), data.len());
+    }
+
+    #[test]
+    fn test_malformed() {
+        let e = parse_input("6,10\n0,14\n\nfold along z=7\n").unwrap_err();
+        assert_eq!((e.line, e.column), (4, 12));
+        let e = parse_input("6,10\n0;14\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert!(parse_input("6,10\n0,14\n").is_err());
+    }
+
+    #[test]
+    fn test_bad_sheets() {
+        let (data, instructions) = parse_input("6,10\n\nfold along x=1\n").unwrap();
+        let e = part1(&data, &instructions).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "folding along x=1 would take the dots at x=6 past the edge"
+        );
+        assert_eq!(part2(&data, &instructions), Err(e));
+        assert_eq!(frames(&data, &instructions).err(), Some(e));
+
+        let input = "100000,100000\n\nfold along x=50000\nfold along y=50000\n";
+        let (data, instructions) = parse_input(input).unwrap();
+        let too_big = SheetError::TooBig {
+            width: 100_001,
+            height: 100_001,
+        };
+        assert_eq!(frames(&data, &instructions).err(), Some(too_big));
+        assert_eq!(part2(&data, &instructions).unwrap(), "#");
+        assert_eq!(sheet_image(&data).err(), Some(too_big));
+        let (data, _) = parse_input("3000000000,0\n\nfold along y=1\n").unwrap();
+        assert!(sheet_image(&data).is_err());
+    }
+
+    type Sheet = (HashSet<[usize; 2]>, Vec<Instruction>);
+
+    fn sheet() -> impl Strategy<Value = Sheet> {
+        let dots = hash_set([0..1500usize, 0..1500], 0..30);
+        let fold = (any::<bool>(), 0..1500usize).prop_map(|(x, n)| match x {
+            true => Instruction::X(n),
+            false => Instruction::Y(n),
+        });
+        (dots, vec(fold, 1..6))
+    }
+
+    fn print((dots, instructions): &Sheet) -> String {
+        let mut s: String = dots.iter().map(|[x, y]| format!("{},{}\n", x, y)).collect();
+        s.push('\n');
+        for i in instructions {
+            match i {
+                Instruction::X(n) => s += &format!("fold along x={}\n", n),
+                Instruction::Y(n) => s += &format!("fold along y={}\n", n),
+            }
+        }
+        s
+    }
+
+    proptest! {
+        #[test]
+        fn prop_print_then_parse(sheet in sheet()) {
+            prop_assert_eq!(parse_input(&print(&sheet)), Ok(sheet));
+        }
+
+        #[test]
+        fn prop_missing_blank_line(sheet in sheet()) {
+            let text = print(&sheet).replacen("\n\n", "\n", 1);
+            let text = text.strip_prefix('\n').unwrap_or(&text);
+            prop_assert!(parse_input(text).is_err(), "{}", text);
+        }
+
+        #[test]
+        fn prop_bad_axis(sheet in sheet(), i in any::<Index>(), axis in "[a-wz ]") {
+            let text = print(&sheet);
+            let folds: Vec<_> = text.match_indices('=').map(|(i, _)| i - 1).collect();
+            let i = folds[i.index(folds.len())];
+            let broken = format!("{}{}{}", &text[..i], axis, &text[i + 1..]);
+            prop_assert!(parse_input(&broken).is_err(), "{}", broken);
+        }
+
+        #[test]
+        fn prop_bad_number(sheet in sheet(), i in any::<Index>(), n in "-1|x|") {
+            let text = print(&sheet);
+            let digit = |c: char| c.is_ascii_digit();
+            let numbers: Vec<_> = text.match_indices(digit).map(|(i, _)| i).collect();
+            let start = numbers[i.index(numbers.len())];
+            let end = start + text[start..].find(|c| !digit(c)).unwrap();
+            let start = text[..start].rfind(|c| !digit(c)).map_or(0, |i| i + 1);
+            let broken = format!("{}{}{}", &text[..start], n, &text[end..]);
+            prop_assert!(parse_input(&broken).is_err(), "{}", broken);
+        }
+
+        #[test]
+        fn prop_garbage_does_not_panic(text in "[0-9,\n fold alngxy=]{0,60}|\\PC{0,40}") {
+            let _ = parse_input(&text);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::collections::HashSet;
+
+use aoc_common::{one_arg, Session};
+
+use crate::{bounds, fold, frame, Instruction};
+
+/// Sheets wider than this are summarised instead of printed.
+const MAX_WIDTH: usize = 100;
+
+/// The sheet, folded a fold at a time.
+pub(crate) struct Sheet {
+    dots: HashSet<[usize; 2]>,
+    instructions: Vec<Instruction>,
+    next: usize,
+}
+
+impl Sheet {
+    pub(crate) fn new((dots, instructions): (HashSet<[usize; 2]>, Vec<Instruction>)) -> Self {
+        Self {
+            dots,
+            instructions,
+            next: 0,
+        }
+    }
+}
+
+fn parse_fold(arg: &str) -> Result<Instruction, String> {
+    let n = |n: &str| {
+        n.parse()
+            .map_err(|_| format!("expected a line number, found `{}`", n))
+    };
+    match arg.split_once('=') {
+        Some(("x", line)) => Ok(Instruction::X(n(line)?)),
+        Some(("y", line)) => Ok(Instruction::Y(n(line)?)),
+        _ => Err(format!("expected `x=<n>` or `y=<n>`, found `{}`", arg)),
+    }
+}
+
+impl Session for Sheet {
+    fn help(&self) -> &'static str {
+        "fold: make the next fold of the input\n\
+         fold <x|y>=<n>: fold along another line"
+    }
+
+    fn state(&self) -> String {
+        let summary = format!(
+            "{} dots, {} of {} folds made",
+            self.dots.len(),
+            self.next,
+            self.instructions.len()
+        );
+        let (width, height) = bounds(&self.dots);
+        match width <= MAX_WIDTH {
+            true => match frame(&self.dots) {
+                Ok(sheet) => format!("{}\n{}", summary, sheet.grid_text()),
+                Err(e) => format!("{}\n{}, fold it to see it", summary, e),
+            },
+            false => format!(
+                "{}\nthe sheet is {}x{}, fold it to see it",
+                summary, width, height
+            ),
+        }
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<Option<String>, String> {
+        let (instruction, next) = match (command, args) {
+            ("fold", []) => {
+                let instruction = *self
+                    .instructions
+                    .get(self.next)
+                    .ok_or("every fold of the input is made")?;
+                (instruction, self.next + 1)
+            }
+            ("fold", args) => (
+                parse_fold(one_arg(args, "one fold, like `x=5`")?)?,
+                self.next,
+            ),
+            _ => return Err(format!("unknown command `{}`", command)),
+        };
+        self.dots = fold(&self.dots, instruction).map_err(|e| e.to_string())?;
+        self.next = next;
+        Ok(None)
+    }
+}
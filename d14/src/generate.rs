@@ -0,0 +1,24 @@
+//! Random polymer templates and insertion rules.
+
+use rand::{seq::IndexedRandom, Rng, RngExt};
+
+const ELEMENTS: &[u8] = b"BCFHKNOPSV";
+
+/// A template of `size` elements with a rule for every pair of elements.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let elements = &ELEMENTS[..rng.random_range(2..=ELEMENTS.len())];
+    let mut s: String = (0..size.max(2))
+        .map(|_| char::from(*elements.choose(rng).unwrap()))
+        .collect();
+    s.push_str("\n\n");
+    for a in elements {
+        for b in elements {
+            let c = elements.choose(rng).unwrap();
+            s.extend([*a, *b].map(char::from));
+            s.push_str(" -> ");
+            s.push(char::from(*c));
+            s.push('\n');
+        }
+    }
+    s
+}
@@ -0,0 +1,178 @@
+//! Day 14: Extended Polymerization, growing a polymer by pair insertion.
+#![warn(missing_docs)]
+
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+};
+
+use aoc_common::{ParseError, Solution};
+use itertools::Itertools;
+
+pub mod generate;
+
+/// The element inserted between each pair of elements.
+pub type Rules = HashMap<(char, char), char>;
+
+/// The polymer template, a blank line, then one `AB -> C` rule per line,
+/// with a rule for every pair the polymer can come to hold.
+pub fn parse(s: &str) -> Result<(Vec<char>, Rules), ParseError> {
+    let error = |token: &str, description: &str| ParseError::at(Day14::DAY, s, token, description);
+    let mut lines = s.lines().skip_while(|l| l.trim().is_empty());
+    let template = lines
+        .next()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .ok_or_else(|| error(&s[s.len()..], "expected a polymer template"))?;
+
+    let rules = lines
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let rule = l.trim();
+            match rule.split_once(" -> ") {
+                Some((k, v)) if k.chars().count() == 2 && v.chars().count() == 1 => {
+                    let mut k = k.chars();
+                    Ok((
+                        (k.next().unwrap(), k.next().unwrap()),
+                        (v.chars().next().unwrap(), rule),
+                    ))
+                }
+                _ => Err(error(rule, "expected a rule like `AB -> C`")),
+            }
+        })
+        .collect::<Result<HashMap<_, _>, _>>()?;
+
+    // the pairs of the template, then the pairs each rule makes
+    let mut todo = template
+        .char_indices()
+        .zip(template.chars().skip(1))
+        .map(|((i, a), b)| ((a, b), &template[i..i + a.len_utf8() + b.len_utf8()]))
+        .collect::<Vec<_>>();
+    let mut seen = HashSet::new();
+    while let Some((pair, token)) = todo.pop() {
+        if !seen.insert(pair) {
+            continue;
+        }
+        let &(new, rule) = rules
+            .get(&pair)
+            .ok_or_else(|| error(token, &format!("no rule for the pair `{}{}`", pair.0, pair.1)))?;
+        todo.extend([((pair.0, new), rule), ((new, pair.1), rule)]);
+    }
+    let map = rules.into_iter().map(|(k, (v, _))| (k, v)).collect();
+    Ok((template.chars().collect(), map))
+}
+
+/// The most common element's count minus the least common one's after `n`
+/// steps of insertion.
+///
+/// # Panics
+///
+/// When a pair of the polymer has no rule.
+pub fn partn(data: &[char], map: &HashMap<(char, char), char>, n: usize) -> usize {
+    let mut datamap: HashMap<(char, char), usize> = HashMap::new();
+    let mut value_map: HashMap<char, usize> = HashMap::new();
+    for window in data.windows(2) {
+        *datamap.entry((window[0], window[1])).or_default() += 1;
+    }
+    for c in data {
+        *value_map.entry(*c).or_default() += 1;
+    }
+    for _ in 0..n {
+        let mut new_map = HashMap::new();
+        for (k, v) in datamap.into_iter().flat_map(|(k, v)| {
+            let new = *map.get(&k).unwrap();
+            *value_map.entry(new).or_default() += v;
+            [((k.0, new), v), ((new, k.1), v)]
+        }) {
+            *new_map.entry(k).or_default() += v;
+        }
+        datamap = new_map;
+    }
+    value_map
+        .values()
+        .copied()
+        .minmax()
+        .into_option()
+        .map(|n: (usize, usize)| n.1 - n.0)
+        .unwrap()
+}
+
+/// [`partn`] after 10 steps.
+pub fn part1(data: &[char], map: &HashMap<(char, char), char>) -> usize {
+    partn(data, map, 10)
+}
+
+/// [`partn`] after 40 steps.
+pub fn part2(data: &[char], map: &HashMap<(char, char), char>) -> usize {
+    partn(data, map, 40)
+}
+
+/// The [`Solution`] of day 14.
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input<'a> = (Vec<char>, HashMap<(char, char), char>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(&input.0, &input.1))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(&input.0, &input.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C
+";
+
+    #[test]
+    fn test_parse() {
+        parse(EXAMPLE).unwrap();
+        let e = parse("NN\n\nNN -> C\nCN -> N\n").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 1));
+        assert_eq!(e.description, "no rule for the pair `NC`");
+        assert_eq!(parse("NNB\n\nNN -> N\n").unwrap_err().column, 2);
+    }
+
+    #[test]
+    fn test_part1() {
+        let (data, map) = parse(EXAMPLE).unwrap();
+        assert_eq!(part1(&data, &map), 1588);
+    }
+
+    #[test]
+    fn test_part2() {
+        let (data, map) = parse(EXAMPLE).unwrap();
+        assert_eq!(part2(&data, &map), 2188189693529);
+    }
+}
@@ -0,0 +1,16 @@
+//! Random risk level maps.
+
+use rand::{Rng, RngExt};
+
+/// A `size` by `size` map of risk levels from 1 to 9.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let size = size.max(1);
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size)
+                .map(|_| char::from(b'0' + rng.random_range(1..10)))
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
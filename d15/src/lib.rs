@@ -0,0 +1,228 @@
+//! Day 15: Chiton, finding the path of lowest risk through a cave.
+#![warn(missing_docs)]
+
+use std::error::Error;
+
+use aoc_common::{Cell, Colour, Frame, Grid, Image, ParseError, Picture, Solution, Visualize};
+
+pub mod generate;
+
+/// One row of risk digits per line.
+pub fn parse_maze(maze: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::from_digits(Day15::DAY, maze)
+}
+
+/// The lowest total risk of getting to every position, calling `on_sweep`
+/// after every pass over the maze.
+fn lowest_risks<F: FnMut(&Grid<usize>)>(maze: &Grid<usize>, mut on_sweep: F) -> Grid<usize> {
+    let _span = aoc_common::trace_span!("d15 lowest_risks");
+    let mut sums = Grid::filled(maze.width(), maze.height(), usize::MAX);
+    sums[(0, 0)] = 0;
+    let mut edits = true;
+    let mut sweeps = 0;
+    while edits {
+        edits = false;
+        for (x, y) in maze.positions() {
+            let self_sum = sums[(x, y)];
+            for p in maze.neighbours4(x, y) {
+                let candidate_sum = self_sum + maze[p];
+                if sums[p] > candidate_sum {
+                    edits = true;
+                    sums[p] = candidate_sum;
+                }
+            }
+        }
+        sweeps += 1;
+        on_sweep(&sums);
+    }
+    aoc_common::trace_count!("d15 sweeps", sweeps);
+    sums
+}
+
+/// The lowest total risk from the top left to the bottom right.
+pub fn part1(maze: &Grid<usize>) -> usize {
+    lowest_risks(maze, |_| ())[(maze.width() - 1, maze.height() - 1)]
+}
+
+/// Walks back from the bottom right along the lowest risks.
+fn path(maze: &Grid<usize>, sums: &Grid<usize>) -> Vec<(usize, usize)> {
+    let mut p = (maze.width() - 1, maze.height() - 1);
+    let mut path = vec![p];
+    while p != (0, 0) {
+        p = maze
+            .neighbours4(p.0, p.1)
+            .find(|&q| sums[q] + maze[p] == sums[p])
+            .expect("every risk but the first comes from a neighbour");
+        path.push(p);
+    }
+    path
+}
+
+/// The maze after every pass of the search, with the risks lowered in that
+/// pass in yellow and the positions not reached yet as `.`, then the path
+/// of lowest risk in red.
+pub fn frames(maze: &Grid<usize>) -> Vec<Frame> {
+    let digit = |n: usize| char::from_digit(n as u32, 10).unwrap_or('+');
+    let mut frames = Vec::new();
+    let mut last = Grid::filled(maze.width(), maze.height(), usize::MAX);
+    let sums = lowest_risks(maze, |sums| {
+        let cells = Grid::from_fn(maze.width(), maze.height(), |x, y| {
+            match (sums[(x, y)], last[(x, y)]) {
+                (usize::MAX, _) => Cell::from('.'),
+                (now, before) if now < before => Cell::new(digit(maze[(x, y)]), Colour::Yellow),
+                _ => Cell::from(digit(maze[(x, y)])),
+            }
+        });
+        let lowered = sums
+            .values()
+            .zip(last.values())
+            .filter(|(a, b)| a < b)
+            .count();
+        let caption = format!("pass {}: {} risks lowered", frames.len() + 1, lowered);
+        frames.push(Frame::new(&cells, |&c| c).with_caption(caption));
+        last = sums.clone();
+    });
+    let mut cells = maze.map(|&n| Cell::from(digit(n)));
+    for p in path(maze, &sums) {
+        cells[p].colour = Some(Colour::Red);
+    }
+    let risk = sums[(maze.width() - 1, maze.height() - 1)];
+    frames.push(Frame::new(&cells, |&c| c).with_caption(format!("lowest total risk {}", risk)));
+    frames
+}
+
+fn wrap(mut n: usize) -> usize {
+    while n > 9 {
+        n -= 9
+    }
+    n
+}
+
+/// The lowest total risk through the cave tiled 5 times in each direction,
+/// with the risk growing by 1 for each tile away from the first.
+pub fn part2(maze: &Grid<usize>) -> usize {
+    part1(&full_maze(maze))
+}
+
+/// The whole cave of [`part2`].
+pub fn full_maze(maze: &Grid<usize>) -> Grid<usize> {
+    let x_max = maze.width();
+    let y_max = maze.height();
+    Grid::from_fn(x_max * 5, y_max * 5, |x, y| {
+        wrap(maze[(x % x_max, y % y_max)] + x / x_max + y / y_max)
+    })
+}
+
+/// The risks as shades of grey, brighter for riskier positions, and the
+/// path of lowest risk in red.
+pub fn risk_image(maze: &Grid<usize>) -> Image {
+    let mut pixels = maze.map(|&n| {
+        let shade = (n.min(9) * 28) as u8;
+        [shade, shade, shade]
+    });
+    for p in path(maze, &lowest_risks(maze, |_| ())) {
+        pixels[p] = Colour::Red.rgb();
+    }
+    Image::Rgb(pixels)
+}
+
+/// The [`Solution`] of day 15.
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input<'a> = Grid<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_maze(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input))
+    }
+}
+
+impl Visualize for Day15 {
+    fn frames(input: &Self::Input<'_>) -> Result<Vec<Frame>, Box<dyn Error>> {
+        Ok(frames(input))
+    }
+}
+
+impl Picture for Day15 {
+    fn image(input: &Self::Input<'_>) -> Result<Image, Box<dyn Error>> {
+        Ok(risk_image(&full_maze(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+";
+
+    #[test]
+    fn test_part1() {
+        let maze = parse_maze(EXAMPLE).unwrap();
+        assert_eq!(part1(&maze), 40);
+    }
+
+
+    #[test]
+    fn test_part2() {
+        let maze = parse_maze(EXAMPLE).unwrap();
+        assert_eq!(part2(&maze), 315);
+    }
+
+
+    #[test]
+    fn test_range() {
+        let maze = parse_maze(EXAMPLE).unwrap();
+        assert_eq!(maze.neighbours4(0, 2).count(), 3);
+    }
+
+    #[test]
+    fn test_risk_image() {
+        let maze = parse_maze(EXAMPLE).unwrap();
+        let Image::Rgb(pixels) = risk_image(&maze) else {
+            panic!("the risk map is in colour");
+        };
+        assert_eq!(pixels[(0, 0)], Colour::Red.rgb());
+        assert_eq!(pixels[(9, 0)], [56, 56, 56]);
+        let path = pixels.values().filter(|&&p| p == Colour::Red.rgb()).count();
+        assert_eq!(path, 19);
+    }
+
+    #[test]
+    fn test_frames() {
+        let maze = parse_maze(EXAMPLE).unwrap();
+        let frames = frames(&maze);
+        assert_eq!(frames[0].caption(), "pass 1: 100 risks lowered");
+        let last = frames.last().unwrap();
+        assert_eq!(last.caption(), "lowest total risk 40");
+        let path = last
+            .cells()
+            .values()
+            .filter(|c| c.colour == Some(Colour::Red))
+            .count();
+        assert_eq!(path, 19);
+        assert_eq!(last.grid_text(), EXAMPLE.trim_end());
+    }
+}
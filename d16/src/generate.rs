@@ -0,0 +1,32 @@
+//! Random BITS transmissions.
+
+use rand::{Rng, RngExt};
+
+use crate::Packet;
+
+fn packet<R: Rng + ?Sized>(rng: &mut R, budget: &mut usize, depth: usize) -> Packet {
+    let version = rng.random_range(0..8);
+    *budget = budget.saturating_sub(1);
+    if *budget == 0 || depth >= 6 || rng.random_bool(0.3) {
+        return Packet::literal(version, rng.random_range(0..1000));
+    }
+    let type_id = [0, 1, 2, 3, 5, 6, 7][rng.random_range(0..7)];
+    let children = match type_id {
+        // products only multiply literals, so that the value fits in a usize
+        // and the transmission has an answer
+        1 => (0..rng.random_range(1..=3))
+            .map(|_| Packet::literal(rng.random_range(0..8), rng.random_range(0..1000)))
+            .collect(),
+        5..=7 => (0..2).map(|_| packet(rng, budget, depth + 1)).collect(),
+        _ => (0..rng.random_range(1..=4))
+            .map(|_| packet(rng, budget, depth + 1))
+            .collect(),
+    };
+    Packet::operator(version, type_id, children)
+}
+
+/// One transmission of about `size` packets, written as hex.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut budget = size.max(1);
+    packet(rng, &mut budget, 0).to_hex() + "\n"
+}
@@ -0,0 +1,433 @@
+//! Day 16: Packet Decoder, reading the BITS transmission format.
+//!
+//! ```
+//! use d16::{parse_packet, Packet};
+//!
+//! assert_eq!(parse_packet("C200B40A82").unwrap().calculate(), Some(3));
+//!
+//! let sum = Packet::operator(1, 0, vec![Packet::literal(0, 1), Packet::literal(0, 2)]);
+//! assert_eq!(parse_packet(&sum.to_hex()).unwrap(), sum);
+//! ```
+#![warn(missing_docs)]
+
+use std::error::Error;
+
+use aoc_common::{Explore, ParseError, Session, Solution};
+use bitvec::prelude::*;
+use hex::{FromHexError, decode};
+use either::Either;
+
+pub mod generate;
+mod repl;
+
+/// A transmission, most significant bit first.
+pub type Hexa = BitVec<u8, Msb0>;
+/// A part of a [`Hexa`] transmission.
+pub type HexaSlice = BitSlice<u8, Msb0>;
+
+/// A packet, either a literal value or an operator over sub-packets.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Packet {
+    version: usize,
+    type_id: u8,
+    containts: Either<Vec<Packet>, usize>,
+}
+
+impl Packet {
+    fn new_non_4(version: usize, type_id: u8) -> Self {
+        Self {
+            version,
+            type_id,
+            containts: Either::Left(Vec::new()),
+        }
+    }
+
+    fn new_4(version: usize, type_id: u8, value: usize) -> Self {
+        Self {
+            version,
+            type_id,
+            containts: Either::Right(value),
+        }
+    }
+
+    /// A literal value packet, type 4.
+    pub fn literal(version: usize, value: usize) -> Self {
+        Self::new_4(version, 4, value)
+    }
+
+    /// An operator packet of `type_id` over `packets`.
+    pub fn operator(version: usize, type_id: u8, packets: Vec<Packet>) -> Self {
+        let mut packet = Self::new_non_4(version, type_id);
+        packet.extend(packets);
+        packet
+    }
+
+    /// Reads the packet at the start of `message` and moves `message` past
+    /// it. On errors `message` is left where decoding stopped.
+    pub fn decode(message: &mut &HexaSlice) -> Result<Self, &'static str> {
+        packets(message)
+    }
+
+    /// The version from the header, 0 to 7.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// The type from the header, 4 for literals.
+    pub fn type_id(&self) -> u8 {
+        self.type_id
+    }
+
+    /// The value of a literal packet.
+    pub fn value(&self) -> Option<usize> {
+        self.containts.as_ref().right().copied()
+    }
+
+    /// The sub-packets of an operator packet, empty for literals.
+    pub fn sub_packets(&self) -> &[Packet] {
+        self.containts.as_ref().left().map_or(&[], Vec::as_slice)
+    }
+
+    fn extend(&mut self, packet: Vec<Packet>) {
+        match &mut self.containts {
+            Either::Left(v) => v.extend(packet),
+            Either::Right(_) => unreachable!("A packet with value 4 should not be pushed."),
+        }
+    }
+
+    /// The sum of the versions of this packet and every packet inside it.
+    pub fn version_sum(&self) -> usize {
+        self.version + match &self.containts {
+            Either::Left(packets) => packets.iter().map(Packet::version_sum).sum(),
+            Either::Right(_) => 0,
+        }
+    }
+
+    /// Evaluates the expression the packet stands for, `None` when a sum or
+    /// product doesn't fit in a `usize`.
+    ///
+    /// # Panics
+    ///
+    /// On unknown types, on minimums and maximums without sub-packets and on
+    /// comparisons without two of them.
+    pub fn calculate(&self) -> Option<usize> {
+        match &self.containts {
+            Either::Left(packets) => {
+                let values = packets
+                    .iter()
+                    .map(Packet::calculate)
+                    .collect::<Option<Vec<_>>>()?;
+                match self.type_id {
+                    0 => values.iter().try_fold(0usize, |sum, &v| sum.checked_add(v)),
+                    1 => values.iter().try_fold(1usize, |product, &v| product.checked_mul(v)),
+                    2 => Some(*values.iter().min().unwrap()),
+                    3 => Some(*values.iter().max().unwrap()),
+                    5 => Some((values[0] > values[1]) as usize),
+                    6 => Some((values[0] < values[1]) as usize),
+                    7 => Some((values[0] == values[1]) as usize),
+                    n => unreachable!("Packet id: {}, is unknown.", n)
+                }
+            }
+            Either::Right(value) => Some(*value),
+        }
+    }
+
+    /// Writes the packet in the BITS format. Operators with an odd version
+    /// give their number of sub-packets and even ones their length in bits,
+    /// so that both forms get written.
+    pub fn encode(&self, bits: &mut Hexa) {
+        push_bits(bits, self.version, 3);
+        push_bits(bits, self.type_id as usize, 3);
+        match &self.containts {
+            Either::Left(packets) => {
+                let mut sub = Hexa::new();
+                packets.iter().for_each(|p| p.encode(&mut sub));
+                match self.version % 2 == 1 || sub.len() >= 1 << 15 {
+                    true => {
+                        bits.push(true);
+                        push_bits(bits, packets.len(), 11);
+                    }
+                    false => {
+                        bits.push(false);
+                        push_bits(bits, sub.len(), 15);
+                    }
+                }
+                bits.extend_from_bitslice(&sub);
+            }
+            Either::Right(value) => {
+                let groups = (usize::BITS - value.leading_zeros()).div_ceil(4).max(1);
+                for g in (0..groups).rev() {
+                    bits.push(g > 0);
+                    push_bits(bits, (value >> (4 * g)) & 0xf, 4);
+                }
+            }
+        }
+    }
+
+    /// The packet as an upper case hex transmission, padded with zeros.
+    pub fn to_hex(&self) -> String {
+        let mut bits = Hexa::new();
+        self.encode(&mut bits);
+        bits.resize(bits.len().next_multiple_of(8), false);
+        hex::encode_upper(bits.as_raw_slice())
+    }
+}
+
+fn push_bits(bits: &mut Hexa, value: usize, width: usize) {
+    (0..width).rev().for_each(|i| bits.push((value >> i) & 1 == 1));
+}
+
+/// Decodes a hex transmission into its bits.
+pub fn parse(s: &str) -> Result<Hexa, ParseError> {
+    let decoded = decode(s).map_err(|e| match e {
+        FromHexError::InvalidHexCharacter { index, .. } => {
+            let token = s.get(index..).unwrap_or_default();
+            let c = token.chars().next().unwrap_or_default();
+            ParseError::at(Day16::DAY, s, token, format!("expected a hex digit, found `{}`", c))
+        }
+        e => ParseError::at(Day16::DAY, s, &s[s.len()..], e.to_string()),
+    })?;
+    let bv = Hexa::from_slice(&decoded);
+    Ok(bv)
+}
+
+/// Decodes a hex transmission into its outermost packet.
+pub fn parse_packet(s: &str) -> Result<Packet, ParseError> {
+    let bits = parse(s)?;
+    let mut message = bits.as_bitslice();
+    Packet::decode(&mut message).map_err(|e| {
+        let digit = (bits.len() - message.len()) / 4;
+        ParseError::at(Day16::DAY, s, &s[digit.min(s.len())..], e)
+    })
+}
+
+fn take<'a>(message: &mut &'a HexaSlice, len: usize) -> Result<&'a HexaSlice, &'static str> {
+    if message.len() < len {
+        return Err("the transmission ends inside a packet");
+    }
+    let taken;
+    (taken, *message) = message.split_at(len);
+    Ok(taken)
+}
+
+fn header_version(message: &mut &HexaSlice) -> Result<(usize, u8), &'static str> {
+    let (version, type_id) = take(message, 6)?.split_at(3);
+    Ok((version.load_be(), type_id.load_be()))
+}
+
+fn packet_id_4(message: &mut &HexaSlice) -> Result<usize, &'static str> {
+    let mut packet = BitVec::<u8, Msb0>::new();
+    loop {
+        let (cont, part) = take(message, 5)?.split_at(1);
+        packet.extend_from_bitslice(part);
+        if packet.len() > usize::BITS as usize {
+            return Err("a literal value does not fit in 64 bits");
+        }
+        if !cont.any() {
+            break;
+        }
+    }
+    Ok(packet.load_be())
+}
+
+fn operator_packets(message: &mut &HexaSlice) -> Result<Vec<Packet>, &'static str> {
+    match take(message, 1)?.any() {
+        true => {
+            let nr_sub_packets: usize = take(message, 11)?.load_be();
+            let mut sub_packets = Vec::new();
+            for _ in 0..nr_sub_packets {
+                sub_packets.push(packets(message)?);
+            }
+            Ok(sub_packets)
+        },
+        false => {
+            let packet_len: usize = take(message, 15)?.load_be();
+            let len = message.len();
+            let mut sub_packets = Vec::new();
+            while len - message.len() < packet_len {
+                sub_packets.push(packets(message)?);
+            }
+            match len - message.len() == packet_len {
+                true => Ok(sub_packets),
+                false => Err("the sub-packets are longer than their operator"),
+            }
+        },
+    }
+}
+
+fn packets(message: &mut &HexaSlice) -> Result<Packet, &'static str> {
+    let (version, type_id) = header_version(message)?;
+    if type_id == 4 {
+        return Ok(Packet::new_4(version, type_id, packet_id_4(message)?));
+    }
+    let sub_packets = operator_packets(message)?;
+    match (type_id, sub_packets.len()) {
+        (0 | 1, _) | (2 | 3, 1..) | (5..=7, 2) => Ok(Packet::operator(version, type_id, sub_packets)),
+        (2 | 3, _) => Err("a minimum or maximum needs sub-packets"),
+        _ => Err("a comparison needs two sub-packets"),
+    }
+}
+
+/// The version sum of the outermost packet.
+pub fn part1(packet: &Packet) -> usize {
+    packet.version_sum()
+}
+
+/// The value of the outermost packet.
+pub fn part2(packet: &Packet) -> Result<usize, &'static str> {
+    packet
+        .calculate()
+        .ok_or("the value of the transmission does not fit in 64 bits")
+}
+
+/// The [`Solution`] of day 16.
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input<'a> = Packet;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_packet(input.trim())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input)?)
+    }
+}
+
+impl Explore for Day16 {
+    fn session(input: Self::Input<'_>) -> Box<dyn Session + '_> {
+        Box::new(repl::Transmission::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*, sample::Index};
+
+    use super::*;
+
+    static T_1: &str = "D2FE28";
+
+    fn parsed() -> Hexa {
+        parse(T_1).unwrap()
+    }
+
+
+    #[test]
+    fn test_parse_input() {
+        assert!(parse(include_str!("../../inputs/meltinglava/d16.txt").trim()).is_ok())
+    }
+
+    #[test]
+    fn test_parse_t_1() {
+        assert!(parse(T_1).is_ok())
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        assert_eq!(parse("D2FX28").unwrap_err().column, 4);
+        assert!(parse("D2F").is_err());
+    }
+
+    #[test]
+    fn test_parse_t_1_correct() {
+        let check = bitvec![u8, Msb0; 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0];
+        assert_eq!(parse(T_1), Ok(check));
+    }
+
+    #[test]
+    fn test_headers() {
+        let parsed = parsed();
+        let mut p = parsed.as_bitslice();
+        let check = bitvec![u8, Msb0; 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0];
+        let actual = header_version(&mut p).unwrap();
+        let expected = (6usize, 4u8, check.as_bitslice());
+        assert_eq!((actual.0, actual.1, p), expected);
+    }
+
+    #[test]
+    fn test_id_4() {
+        let parsed = parsed();
+        let mut p = &parsed.as_bitslice()[6..];
+        assert_eq!(packet_id_4(&mut p), Ok(2021))
+    }
+
+    #[test]
+    fn test_name() {
+        let tests = [
+            ("8A004A801A8002F478", 16),  //represents an operator packet (version 4) which contains an operator packet (version 1) which contains an operator packet (version 5) which contains a literal value (version 6); this packet has a version sum of 16.
+            ("620080001611562C8802118E34", 12),  //represents an operator packet (version 3) which contains two sub-packets; each sub-packet is an operator packet that contains two literal values. This packet has a version sum of 12.
+            ("C0015000016115A2E0802F182340", 23),  //has the same structure as the previous example, but the outermost packet uses a different length type ID. This packet has a version sum of 23.
+            ("A0016C880162017C3686B18A3D4780", 31)  //is an operator packet that contains an operator packet that contains an operator packet that contains five literal values; it has a version sum of 31
+        ];
+        for (hex_string, expexted) in tests {
+            let packet = parse_packet(hex_string).unwrap();
+            assert_eq!(part1(&packet), expexted);
+        }
+    }
+
+    #[test]
+    fn test_too_large() {
+        let big = || Packet::literal(0, usize::MAX);
+        let product = Packet::operator(0, 1, vec![big(), Packet::literal(0, 2)]);
+        assert_eq!(
+            part2(&product),
+            Err("the value of the transmission does not fit in 64 bits")
+        );
+        let sum = Packet::operator(0, 0, vec![big(), Packet::literal(0, 1)]);
+        assert_eq!(sum.calculate(), None);
+        let max = Packet::operator(0, 3, vec![big(), Packet::literal(0, 1)]);
+        assert_eq!(part2(&max), Ok(usize::MAX));
+    }
+
+    fn packet() -> impl Strategy<Value = Packet> {
+        let literal = (0..8usize, any::<usize>()).prop_map(|(v, n)| Packet::literal(v, n));
+        literal.prop_recursive(4, 32, 4, |inner| {
+            prop_oneof![
+                (0..8usize, 0..4u8, vec(inner.clone(), 1..=4)),
+                (0..8usize, 5..8u8, vec(inner, 2)),
+            ]
+            .prop_map(|(version, type_id, packets)| Packet::operator(version, type_id, packets))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_calculate_does_not_panic(packet in packet()) {
+            let _ = packet.calculate();
+        }
+
+        #[test]
+        fn prop_encode_then_parse(packet in packet()) {
+            prop_assert_eq!(parse_packet(&packet.to_hex()), Ok(packet));
+        }
+
+        #[test]
+        fn prop_truncated(packet in packet(), i in any::<Index>()) {
+            let mut bits = Hexa::new();
+            packet.encode(&mut bits);
+            let hex = packet.to_hex();
+            let broken = &hex[..2 * i.index((bits.len() - 1) / 8 + 1)];
+            prop_assert!(parse_packet(broken).is_err(), "{}", broken);
+        }
+
+        #[test]
+        fn prop_not_hex(hex in "[0-9A-F]{0,10}[G-Zg-z ][0-9A-F]{0,10}") {
+            prop_assert!(parse_packet(&hex).is_err());
+        }
+
+        #[test]
+        fn prop_garbage_does_not_panic(text in "([0-9A-F]{2}){0,20}|\\PC{0,40}") {
+            let _ = parse_packet(&text);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use std::fmt::Write;
+
+use aoc_common::{one_arg, Session};
+
+use crate::{parse_packet, Packet};
+
+/// The packet being looked at.
+pub(crate) struct Transmission {
+    packet: Packet,
+}
+
+impl Transmission {
+    pub(crate) fn new(packet: Packet) -> Self {
+        Self { packet }
+    }
+}
+
+/// The value of `packet`, if it fits.
+fn value(packet: &Packet) -> String {
+    packet
+        .calculate()
+        .map_or_else(|| "more than 64 bits".to_string(), |v| v.to_string())
+}
+
+fn operation(type_id: u8) -> &'static str {
+    match type_id {
+        0 => "sum",
+        1 => "product",
+        2 => "minimum",
+        3 => "maximum",
+        4 => "literal",
+        5 => "greater than",
+        6 => "less than",
+        _ => "equal to",
+    }
+}
+
+/// One line per packet, sub-packets indented below their operator.
+fn tree(packet: &Packet, depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    let _ = match packet.value() {
+        Some(value) => writeln!(out, "{}v{} literal {}", indent, packet.version(), value),
+        None => writeln!(
+            out,
+            "{}v{} {} = {}",
+            indent,
+            packet.version(),
+            operation(packet.type_id()),
+            value(packet)
+        ),
+    };
+    for sub in packet.sub_packets() {
+        tree(sub, depth + 1, out);
+    }
+}
+
+impl Session for Transmission {
+    fn help(&self) -> &'static str {
+        "decode <hex>: look at another transmission"
+    }
+
+    fn state(&self) -> String {
+        let mut s = format!(
+            "version sum {}, value {}\n",
+            self.packet.version_sum(),
+            value(&self.packet)
+        );
+        tree(&self.packet, 0, &mut s);
+        s.pop();
+        s
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<Option<String>, String> {
+        match command {
+            "decode" => {
+                let hex = one_arg(args, "one hex string")?;
+                self.packet = parse_packet(hex).map_err(|e| e.to_string())?;
+                Ok(None)
+            }
+            _ => Err(format!("unknown command `{}`", command)),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Random target areas.
+
+use rand::{Rng, RngExt};
+
+/// A target area to the right of and below the probe, reaching up to `size`
+/// steps away.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let far = size.max(20) as i64;
+    let x1 = rng.random_range(5..far / 2);
+    let x2 = rng.random_range(x1 + 1..=far);
+    let y1 = rng.random_range(-far..-5);
+    let y2 = rng.random_range(y1 + 1..=-1);
+    format!("target area: x={}..{}, y={}..{}\n", x1, x2, y1, y2)
+}
@@ -0,0 +1,28 @@
+//! Random snailfish homework.
+
+use rand::{Rng, RngExt};
+
+fn pair<R: Rng + ?Sized>(rng: &mut R, depth: usize, s: &mut String) {
+    s.push('[');
+    element(rng, depth, s);
+    s.push(',');
+    element(rng, depth, s);
+    s.push(']');
+}
+
+fn element<R: Rng + ?Sized>(rng: &mut R, depth: usize, s: &mut String) {
+    match depth < 4 && rng.random_bool(0.6) {
+        true => pair(rng, depth + 1, s),
+        false => s.push(char::from(b'0' + rng.random_range(0..10))),
+    }
+}
+
+/// `size` reduced snailfish numbers, nested at most four pairs deep.
+pub fn input<R: Rng + ?Sized>(rng: &mut R, size: usize) -> String {
+    let mut s = String::new();
+    for _ in 0..size.max(1) {
+        pair(rng, 1, &mut s);
+        s.push('\n');
+    }
+    s
+}
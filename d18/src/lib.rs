@@ -0,0 +1,501 @@
+//! Day 18: Snailfish, adding up snailfish numbers.
+//!
+//! ```
+//! use d18::SnailFish;
+//!
+//! let a: SnailFish = "[[[[4,3],4],4],[7,[[8,4],9]]]".parse().unwrap();
+//! let b: SnailFish = "[1,1]".parse().unwrap();
+//! let sum = a + b;
+//! assert_eq!(sum.to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+//! assert_eq!(sum.magnitude(), 1384);
+//! ```
+#![warn(missing_docs)]
+
+use std::{str::FromStr, fmt::{Display, Debug}, ops::{Not, Add, AddAssign}, iter::{Sum, repeat_with}, error::Error};
+
+use aoc_common::{parse_at, parse_lines_with, Explore, ParseError, Session, Solution};
+use slice_group_by::StrGroupBy;
+
+pub mod generate;
+mod repl;
+
+#[derive(Clone, PartialEq, Eq)]
+enum SFValue {
+    Number(usize),
+    Sf(Box<SFValue>, Box<SFValue>)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right
+}
+
+impl Not for Side {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        match self {
+            Side::Right => Side::Left,
+            Side::Left => Side::Right,
+        }
+    }
+}
+
+impl SFValue {
+    fn magnitude(&self) -> usize {
+        match self {
+            SFValue::Number(n) => *n,
+            SFValue::Sf(l, r) => 3 * l.magnitude() + 2 * r.magnitude(),
+        }
+    }
+
+    fn find_nested_inside_for_parirs(&self, mut path: Vec<Side>) -> Option<Vec<Side>> {
+        match self {
+            SFValue::Number(_) => {
+                match path.len() {
+                    0..=4 => None,
+                    5.. => Some(path)
+                }
+            },
+            SFValue::Sf(left, right) => {
+                let mut l = path.clone();
+                l.push(Side::Left);
+                let ans = left.find_nested_inside_for_parirs(l);
+                path.push(Side::Right);
+                ans.or(right.find_nested_inside_for_parirs(path))
+            }
+        }
+    }
+
+    fn number_greater_than_10(&self, mut path: Vec<Side>) -> Option<Vec<Side>> {
+        match self {
+            SFValue::Number(n) => if *n >= 10 {
+                Some(path)
+            } else { None },
+            SFValue::Sf(left, right) => {
+                let mut l = path.clone();
+                l.push(Side::Left);
+                let ans = left.number_greater_than_10(l);
+                path.push(Side::Right);
+                ans.or(right.number_greater_than_10(path))
+            }
+        }
+    }
+
+    fn partial_parse<'a, T>(text: &str, symbols: &mut T) -> Result<Self, ParseError>
+    where T: Iterator<Item=&'a str> {
+        let left = match symbols.next().ok_or_else(|| unexpected_end(text))? {
+            "[" => Self::partial_parse(text, symbols)?,
+            n => {
+                return Ok(SFValue::Number(parse_at(Day18::DAY, text, n)?));
+            }
+        };
+        expect(text, symbols, ",")?;
+        let right = Self::partial_parse(text, symbols)?;
+        expect(text, symbols, "]")?;
+        Ok(Self::Sf(Box::new(left), Box::new(right)))
+    }
+}
+
+fn unexpected_end(text: &str) -> ParseError {
+    ParseError::at(Day18::DAY, text, &text[text.len()..], "unexpected end of snailfish number")
+}
+
+fn expect<'a, T>(text: &str, symbols: &mut T, expected: &str) -> Result<(), ParseError>
+where T: Iterator<Item=&'a str> {
+    match symbols.next() {
+        Some(s) if s == expected => Ok(()),
+        Some(s) => Err(ParseError::at(Day18::DAY, text, s, format!("expected `{}`, found `{}`", expected, s))),
+        None => Err(unexpected_end(text)),
+    }
+}
+
+impl Debug for SFValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SFValue::Number(n) => write!(f, "{n}"),
+            SFValue::Sf(l, r) => write!(f, "[{l},{r}]"),
+        }
+    }
+}
+
+impl Display for SFValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SFValue::Number(n) => write!(f, "{n}"),
+            SFValue::Sf(l, r) => write!(f, "[{l},{r}]"),
+        }
+    }
+}
+
+/// A snailfish number, a pair of numbers or pairs.
+///
+/// Adding keeps the result reduced, and summing panics on an empty iterator.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SnailFish {
+    sf: SFValue,
+}
+
+impl SnailFish {
+    /// Three times the magnitude of the left element plus two times the
+    /// right one, where a regular number is its own magnitude.
+    pub fn magnitude(&self) -> usize {
+        self.sf.magnitude()
+    }
+
+    /// Explodes pairs nested inside four pairs and splits numbers of 10 or
+    /// more until neither is left.
+    pub fn reduce(&mut self) {
+        let _span = aoc_common::trace_span!("d18 reduce");
+        let (mut explodes, mut splits) = (0, 0);
+        'main: loop {
+            while let Some(path) = self.sf.find_nested_inside_for_parirs(Vec::new()) {
+                explodes += 1;
+                self.explode(&path);
+            }
+            if let Some(path) = self.sf.number_greater_than_10(Vec::new()) {
+                splits += 1;
+                self.split(&path);
+                continue 'main;
+            }
+            break;
+        }
+        aoc_common::trace_count!("d18 explodes", explodes);
+        aoc_common::trace_count!("d18 splits", splits);
+    }
+
+    fn split(&mut self, path: &[Side]) {
+        let node = self.index_mut(path);
+        let n = match node {
+            SFValue::Sf(_, _) => unreachable!("On split we got a Sf, not a number"),
+            SFValue::Number(n) => {
+                *n
+            },
+        };
+        *node = SFValue::Sf(Box::new(SFValue::Number(n/2)), Box::new(SFValue::Number(n/2 + n%2)));
+    }
+
+    fn explode(&mut self, path: &[Side]) {
+        let (left_value, right_value): (usize, usize) = match self.index(&path[..4]) {
+            SFValue::Number(_) => unreachable!("expected Sf, found number"),
+            SFValue::Sf(l, r) => {
+                (match **l {
+                    SFValue::Number(n) => n,
+                    SFValue::Sf(_, _) => unreachable!("expected number, found Sf"),
+                }, match **r {
+                    SFValue::Number(n) => n,
+                    SFValue::Sf(_, _) => unreachable!("expected number, found Sf"),
+                })
+            },
+        };
+        let base_path = &path[..4];
+        for (v, s) in [
+            (left_value, Side::Left),
+            (right_value, Side::Right)
+        ] {
+            let mut find_path = base_path.to_vec();
+            find_path.push(s);
+            if let Some(p) = self.find(&find_path, s) {
+                match self.index_mut(&p) {
+                    SFValue::Number(ref mut n) => *n += v,
+                    SFValue::Sf(_, _) => unreachable!("exppected number, found Sf"),
+                }
+            }
+        }
+        *self.index_mut(&path[..4]) = SFValue::Number(0);
+    }
+
+    fn index(&self, path: &[Side]) -> &SFValue {
+        let mut current = &self.sf;
+        for p in path {
+            match current {
+                SFValue::Number(_) => (),
+                SFValue::Sf(l, r) => {
+                    current = match p {
+                        Side::Left => l,
+                        Side::Right => r,
+                    }
+                },
+            }
+        }
+        current
+    }
+
+    fn index_mut(&mut self, path: &[Side]) -> &mut SFValue {
+        let mut current = &mut self.sf;
+        for p in path {
+            match current {
+                SFValue::Number(_) => (),
+                SFValue::Sf(l, r) => {
+                    current = match p {
+                        Side::Left => l,
+                        Side::Right => r,
+                    }
+                },
+            }
+        }
+        current
+    }
+
+
+    fn find(&self, path: &[Side], side: Side) -> Option<Vec<Side>> {
+        let mut sides = path.to_vec();
+        let mut len = 0;
+        let mut edits = false;
+        for (n, s) in sides.iter_mut().rev().enumerate() {
+            if *s != side {
+                *s = side;
+                len = n;
+                edits = true;
+                break;
+            }
+        }
+        match edits {
+            true => {
+                sides.iter_mut().skip(path.len() - len).for_each(|s| *s = !side);
+                sides.push(!side);
+                (0..sides.len()).find(|&n| match self.index(&sides[0..n]) {
+                    SFValue::Number(_) => true,
+                    SFValue::Sf(_, _) => false,
+                }).map(|n| sides[0..n].to_vec())
+            }
+            false => None,
+        }
+
+    }
+}
+
+impl Debug for SnailFish {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl Add for SnailFish {
+    type Output = SnailFish;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut ans = Self {
+            sf: SFValue::Sf(Box::new(self.sf), Box::new(rhs.sf)),
+        };
+        ans.reduce();
+        ans
+    }
+}
+
+impl AddAssign for SnailFish {
+    fn add_assign(&mut self, rhs: Self) {
+        let mut temp = SFValue::Number(1);
+        std::mem::swap(&mut self.sf, &mut temp);
+        self.sf = SFValue::Sf(Box::new(temp), Box::new(rhs.sf));
+        self.reduce();
+    }
+}
+
+impl Sum for SnailFish {
+    fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        let mut a = iter.next().unwrap();
+        for i in iter {
+            a += i;
+        }
+        a
+    }
+}
+
+impl Display for SnailFish {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&&self.sf, f)
+    }
+}
+
+impl FromStr for SnailFish {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut symbols = text.trim().linear_group_by_key(char::is_alphanumeric).flat_map(|s| -> Box<dyn Iterator<Item=&str>> {
+            match s.chars().all(char::is_alphanumeric) {
+                true => Box::new(std::iter::once(s)),
+                false => Box::new(s.char_indices().map(move |(i, c)| &s[i..i + c.len_utf8()])),
+            }
+        });
+        let sf = SFValue::partial_parse(text, &mut symbols)?;
+        match symbols.next() {
+            Some(rest) => Err(ParseError::at(Day18::DAY, text, rest, format!("unexpected `{}` after snailfish number", rest))),
+            None => Ok(Self { sf }),
+        }
+    }
+}
+
+/// One snailfish number per line.
+pub fn parse_input(s: &str) -> Result<Vec<SnailFish>, ParseError> {
+    let numbers = parse_lines_with(s, str::parse)?;
+    match numbers.is_empty() {
+        true => Err(ParseError::at(Day18::DAY, s, &s[s.len()..], "expected at least one snailfish number")),
+        false => Ok(numbers),
+    }
+}
+
+
+/// The magnitude of the sum of every number.
+pub fn part1(sf: &[SnailFish]) -> usize {
+    sf.iter().cloned().sum::<SnailFish>().magnitude()
+}
+
+/// The largest magnitude of the sum of two of the numbers.
+pub fn part2(sf: &[SnailFish]) -> usize {
+    sf.iter().flat_map(|f| repeat_with(|| f.clone()).zip(sf.iter().cloned())).map(|(x, y)| x + y).map(|sf| sf.magnitude()).max().unwrap()
+}
+
+/// The [`Solution`] of day 18.
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input<'a> = Vec<SnailFish>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(part2(input))
+    }
+}
+
+impl Explore for Day18 {
+    fn session(input: Self::Input<'_>) -> Box<dyn Session + '_> {
+        Box::new(repl::Homework::new(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prelude::*, sample::Index};
+
+    use super::*;
+
+    const EXAMPLE_1: &[&str] = &[
+        "[1,2]",
+        "[[1,2],3]",
+        "[9,[8,7]]",
+        "[[1,9],[8,5]]",
+        "[[[[1,2],[3,4]],[[5,6],[7,8]]],9]",
+        "[[[9,[3,8]],[[0,9],6]],[[[3,7],[4,9]],3]]",
+        "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]",
+    ];
+    const EXAMPLE_2: &[&str] = &[
+        "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
+        "[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
+        "[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]",
+        "[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]",
+        "[7,[5,[[3,8],[1,4]]]]",
+        "[[2,[2,2]],[8,[8,1]]]",
+        "[2,9]",
+        "[1,[[[9,3],9],[[9,0],[0,7]]]]",
+        "[[[5,[7,4]],7],1]",
+        "[[[[4,2],2],6],[8,7]]"
+    ];
+
+    const EXAMPLE_3: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
+
+    fn parse_for_test(s: &'static str) -> SnailFish {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_sum_ans() {
+        let s: SnailFish = EXAMPLE_2.iter().map(|&s| parse_for_test(s)).sum();
+        assert_eq!(s, parse_for_test("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"))
+    }
+
+    #[test]
+    fn test_parse_and_print() {
+        for n in EXAMPLE_1 {
+            assert_eq!(*n, &format!("{}", n.parse::<SnailFish>().unwrap()))
+        }
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        let column = |s: &str| s.parse::<SnailFish>().unwrap_err().column;
+        assert_eq!(column("[1,2"), 5);
+        assert_eq!(column("[1;2]"), 3);
+        assert_eq!(column("[1,x]"), 4);
+        assert_eq!(column("[1,2]]"), 6);
+        assert_eq!(parse_input("[1,2]\n [3,]\n").unwrap_err().column, 5);
+        assert_eq!(parse_input("\n").unwrap_err().line, 2);
+    }
+
+    fn sf_value() -> impl Strategy<Value = SFValue> {
+        (0..100usize).prop_map(SFValue::Number).prop_recursive(6, 64, 2, |inner| {
+            (inner.clone(), inner).prop_map(|(l, r)| SFValue::Sf(Box::new(l), Box::new(r)))
+        })
+    }
+
+    fn snailfish() -> impl Strategy<Value = SnailFish> {
+        (sf_value(), sf_value()).prop_map(|(l, r)| SnailFish {
+            sf: SFValue::Sf(Box::new(l), Box::new(r)),
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_print_then_parse(sf in snailfish()) {
+            prop_assert_eq!(sf.to_string().parse::<SnailFish>(), Ok(sf));
+        }
+
+        // every snailfish number has as many `[` as `]` and `,`
+        #[test]
+        fn prop_missing_symbol(sf in snailfish(), i in any::<Index>()) {
+            let text = sf.to_string();
+            let symbols: Vec<_> = text.match_indices(['[', ']', ',']).map(|(i, _)| i).collect();
+            let i = symbols[i.index(symbols.len())];
+            let broken = format!("{}{}", &text[..i], &text[i + 1..]);
+            prop_assert!(broken.parse::<SnailFish>().is_err(), "{}", broken);
+        }
+
+        #[test]
+        fn prop_truncated(sf in snailfish(), i in any::<Index>()) {
+            let text = sf.to_string();
+            let broken = &text[..i.index(text.len())];
+            prop_assert!(broken.parse::<SnailFish>().is_err(), "{}", broken);
+        }
+
+        #[test]
+        fn prop_garbage_does_not_panic(text in "[\\[\\],0-9 x]{0,40}|\\PC{0,40}") {
+            let _ = text.parse::<SnailFish>();
+        }
+    }
+
+    #[test]
+    fn test_homework() {
+        assert_eq!(part1(&parse_input(EXAMPLE_3).unwrap()), 4140)
+    }
+
+    #[test]
+    fn test_homework_part2() {
+        assert_eq!(part2(&parse_input(EXAMPLE_3).unwrap()), 3993)
+    }
+
+    #[test]
+    fn test_reduce() {
+        assert_eq!(parse_for_test("[[[[4,3],4],4],[7,[[8,4],9]]]") + parse_for_test("[1,1]"), parse_for_test("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"));
+    }
+}
@@ -0,0 +1,69 @@
+use aoc_common::{one_arg, ParseError, Session};
+
+use crate::SnailFish;
+
+/// A running sum of snailfish numbers.
+pub(crate) struct Homework {
+    numbers: Vec<SnailFish>,
+    added: usize,
+    sum: Option<SnailFish>,
+}
+
+impl Homework {
+    pub(crate) fn new(numbers: Vec<SnailFish>) -> Self {
+        Self {
+            sum: numbers.first().cloned(),
+            added: usize::from(!numbers.is_empty()),
+            numbers,
+        }
+    }
+
+    fn add(&mut self, number: SnailFish) {
+        self.sum = Some(match self.sum.take() {
+            Some(sum) => sum + number,
+            None => number,
+        });
+    }
+}
+
+impl Session for Homework {
+    fn help(&self) -> &'static str {
+        "next: add the next number of the input\n\
+         add <number>: add another number\n\
+         set <number>: start over from another number"
+    }
+
+    fn state(&self) -> String {
+        let added = format!("{} of {} input numbers added", self.added, self.numbers.len());
+        match &self.sum {
+            Some(sum) => format!("{}\n{}\nmagnitude {}", added, sum, sum.magnitude()),
+            None => added,
+        }
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Result<Option<String>, String> {
+        let number = || -> Result<SnailFish, String> {
+            one_arg(args, "one snailfish number, like `[[1,2],3]`")?
+                .parse()
+                .map_err(|e: ParseError| e.to_string())
+        };
+        match command {
+            "next" => {
+                let next = self
+                    .numbers
+                    .get(self.added)
+                    .ok_or("every number of the input is added")?
+                    .clone();
+                self.add(next);
+                self.added += 1;
+            }
+            "add" => {
+                let number = number()?;
+                self.add(number);
+            }
+            "set" => self.sum = Some(number()?),
+            _ => return Err(format!("unknown command `{}`", command)),
+        }
+        Ok(None)
+    }
+}